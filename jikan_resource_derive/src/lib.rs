@@ -4,7 +4,7 @@ use proc_macro::TokenStream;
 use std::iter::repeat;
 
 use syn;
-use syn::{Data, DeriveInput, Lit, Meta, Variant};
+use syn::{Data, DeriveInput, Fields, Lit, Meta, Variant};
 
 use quote::quote;
 
@@ -42,18 +42,29 @@ fn impl_resource_macro(ast: &syn::DeriveInput, prefix: Option<String>) -> TokenS
     let gen = match &ast.data {
         Data::Enum(ref data_enum) => {
             let enum_name = &ast.ident;
+            let (unit_variants, value_variants): (Vec<&Variant>, Vec<&Variant>) = data_enum.variants
+                .iter()
+                .partition(|variant| matches!(variant.fields, Fields::Unit));
             let repeated_enum_name = repeat(&ast.ident);
-            let variant_names = data_enum.variants
+            let variant_names = unit_variants
                 .iter()
                 .map(|variant| &variant.ident);
-            let uri_variants = data_enum.variants
+            let uri_variants = unit_variants
                 .iter()
                 .map(|variant| mount_uri(&prefix, variant));
+            // Variants holding a value, such as `Unknown(String)`, use it percent-encoded as their uri
+            let repeated_value_enum_name = repeat(&ast.ident);
+            let value_variant_names = value_variants
+                .iter()
+                .map(|variant| &variant.ident);
+            let repeated_prefix = repeat(&prefix);
             quote! {
                 impl Resource for #enum_name {
                     fn uri(&self) -> String {
                         match self {
                             #(#repeated_enum_name::#variant_names => #uri_variants.to_string(),)*
+                            #(#repeated_value_enum_name::#value_variant_names(value) => format!(
+                                "{}{}", #repeated_prefix, ::percent_encoding::utf8_percent_encode(value, crate::base::FRAGMENT)),)*
                         }
                     }
                 }
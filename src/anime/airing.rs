@@ -0,0 +1,109 @@
+use std::time::Duration;
+
+use crate::season::Season;
+
+/// Weekly slot of an airing anime, parsed from strings such as "Saturdays at 01:00 (JST)".
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Broadcast {
+    pub weekday: Weekday,
+    pub hour: u8,
+    pub minute: u8,
+    /// Abbreviation of the timezone, as sent by Jikan.
+    pub timezone: String,
+}
+
+impl Broadcast {
+    /// Returns the offset from UTC of the timezone in seconds, for the timezones MyAnimeList uses.
+    pub fn utc_offset(&self) -> Option<i32> {
+        match self.timezone.as_str() {
+            "JST" | "KST" => Some(9 * 3600),
+            "CST" => Some(8 * 3600),
+            "UTC" | "GMT" => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of seconds elapsed since Monday 00:00 in the broadcast timezone.
+    pub fn seconds_from_monday(&self) -> u32 {
+        self.weekday.num_days_from_monday() as u32 * 86400 + self.hour as u32 * 3600 + self.minute as u32 * 60
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub fn num_days_from_monday(self) -> u8 {
+        self as u8
+    }
+}
+
+/// Parses broadcasts such as "Saturdays at 01:00 (JST)".
+pub fn parse_broadcast(value: &str) -> Option<Broadcast> {
+    let (day, rest) = value.trim().split_once(" at ")?;
+    let weekday = match day.trim_end_matches('s') {
+        "Monday" => Weekday::Monday,
+        "Tuesday" => Weekday::Tuesday,
+        "Wednesday" => Weekday::Wednesday,
+        "Thursday" => Weekday::Thursday,
+        "Friday" => Weekday::Friday,
+        "Saturday" => Weekday::Saturday,
+        "Sunday" => Weekday::Sunday,
+        _ => return None,
+    };
+    let (time, timezone) = rest.split_once(" (")?;
+    let (hour, minute) = time.split_once(':')?;
+    let hour = hour.parse().ok().filter(|hour| *hour < 24)?;
+    let minute = minute.parse().ok().filter(|minute| *minute < 60)?;
+    let timezone = timezone.strip_suffix(')')?.to_string();
+    Some(Broadcast { weekday, hour, minute, timezone })
+}
+
+/// Parses durations such as "24 min per ep", "1 hr 55 min" or "30 sec per ep".
+///
+/// Returns `None` for durations too long to be counted in seconds.
+pub fn parse_duration(value: &str) -> Option<Duration> {
+    let mut seconds: u64 = 0;
+    let mut found = false;
+    let mut words = value.split_whitespace().peekable();
+    while let Some(word) = words.next() {
+        let amount = match word.parse::<u64>() {
+            Ok(amount) => amount,
+            Err(_) => continue,
+        };
+        let unit = match words.peek()?.trim_end_matches('.') {
+            "hr" | "hrs" | "hour" | "hours" => 3600,
+            "min" | "mins" => 60,
+            "sec" | "secs" => 1,
+            _ => return None,
+        };
+        seconds = seconds.checked_add(amount.checked_mul(unit)?)?;
+        found = true;
+    }
+    if found {
+        Some(Duration::from_secs(seconds))
+    } else {
+        None
+    }
+}
+
+/// Parses seasons such as "Spring 1998".
+pub fn parse_premiered(value: &str) -> Option<Season> {
+    let (season, year) = value.trim().split_once(' ')?;
+    let year = year.parse().ok()?;
+    match season {
+        "Winter" => Some(Season::Winter(year)),
+        "Spring" => Some(Season::Spring(year)),
+        "Summer" => Some(Season::Summer(year)),
+        "Fall" => Some(Season::Fall(year)),
+        _ => None,
+    }
+}
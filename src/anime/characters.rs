@@ -1,17 +1,12 @@
-use reqwest::Client;
-
 use crate::base::VoiceActor;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_body, Result};
+use crate::id::{AnimeId, CharacterId, PersonId};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_characters(mal_id: u32, http_clt: &Client) -> Result<CharactersStaff> {
-    let url = format!("{}/anime/{}/characters_staff", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let characters_staff: CharactersStaff = serde_json::from_str(&body)?;
+pub(crate) async fn find_characters(mal_id: AnimeId, http_clt: &HttpClient) -> Result<CharactersStaff> {
+    let url = format!("{}/anime/{}/characters_staff", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let characters_staff: CharactersStaff = from_body(&body)?;
 
     Ok(characters_staff)
 }
@@ -24,9 +19,9 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnimeCharacter {
-    pub mal_id: u32,
+    pub mal_id: CharacterId,
     pub url: String,
     pub image_url: String,
     pub name: String,
@@ -34,9 +29,9 @@ pub struct AnimeCharacter {
     pub voice_actors: Vec<VoiceActor>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct StaffMember {
-    pub mal_id: u32,
+    pub mal_id: PersonId,
     pub url: String,
     pub image_url: String,
     pub name: String,
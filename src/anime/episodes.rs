@@ -1,52 +1,54 @@
-use reqwest::Client;
-
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_anime_episodes(mal_id: u32, http_clt: &Client) -> Result<Vec<EpisodeInfo>> {
-    let mut page = 1 as u8;
-
-    let response = make_request(mal_id, http_clt, page).await?;
-
-    let mut episodes = response.episodes;
-    let mut total_pages = response.episodes_last_page;
-
-    while total_pages > 1 {
-        total_pages -= 1;
-        page += 1;
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use crate::datetime::DateTime;
+use crate::error::{from_json, Result};
+use crate::id::AnimeId;
+use crate::pagination::Page;
+use crate::request::{self, HttpClient};
+
+pub(crate) async fn find_anime_episodes(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Vec<EpisodeInfo>> {
+    let first_page = find_episodes_page(mal_id, 1, http_clt).await?;
+    let last_page = first_page.last_page.unwrap_or(1);
+
+    let remaining_pages: Vec<Page<EpisodeInfo>> = stream::iter(2..=last_page)
+        .map(|page| find_episodes_page(mal_id, page, http_clt))
+        .buffered(http_clt.concurrency())
+        .try_collect()
+        .await?;
 
-        let mut response = make_request(mal_id, http_clt, page).await?;
-        episodes.append(&mut response.episodes);
+    let mut episodes = first_page.items;
+    for mut page in remaining_pages {
+        episodes.append(&mut page.items);
     }
 
     Ok(episodes)
 }
 
-async fn make_request(mal_id: u32, http_clt: &Client, page: u8) -> Result<Response> {
-    let url = format!("{}/anime/{}/episodes/{}", BASE_URL, mal_id, page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let response: Response = serde_json::from_str(&body)?;
-    Ok(response)
+pub(crate) async fn find_episodes_page(mal_id: AnimeId, page: u16, http_clt: &HttpClient) -> Result<Page<EpisodeInfo>> {
+    let url = format!("{}/anime/{}/episodes/{}", http_clt.base_url(), mal_id, page);
+    let body = request::get(&url, http_clt).await?;
+    let response: Response = from_json(&body)?;
+    Ok(Page {
+        number: page,
+        items: response.episodes,
+        last_page: Some(response.episodes_last_page),
+    })
 }
 
 jikan_response_entity!(
     struct Response {
-        episodes_last_page: u8,
+        episodes_last_page: u16,
         episodes: Vec<EpisodeInfo>,
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EpisodeInfo {
-    pub episode_id: i16,
+    pub episode_id: u32,
     pub title: Option<String>,
     pub title_japanese: Option<String>,
     pub title_romanji: Option<String>,
-    pub aired: Option<String>,
+    pub aired: Option<DateTime>,
     pub filler: bool,
     pub recap: bool,
     pub video_url: Option<String>,
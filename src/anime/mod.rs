@@ -1,32 +1,39 @@
-use reqwest::Client;
+use std::time::Duration;
 
-use crate::{forum, more_info, news, pictures, recommendations, reviews, stats, user_updates};
-use crate::anime::characters::CharactersStaff;
+use crate::{forum, franchise, more_info, news, pictures, recommendations, request, reviews, stats, user_updates,
+            watch_order};
+use crate::anime::airing::Broadcast;
+use crate::anime::characters::{CharactersStaff, StaffMember};
 use crate::anime::episodes::EpisodeInfo;
+use crate::anime::resources::{ExternalLink, Themes};
 use crate::anime::videos::Videos;
-use crate::base::{MALTypeItem, RelatedContent, SourceType};
-use crate::client::BASE_URL;
+use crate::base::{EntityRef, MALTypeItem, RelatedContent, Relation, SourceType};
+use crate::datetime::DateTime;
+use crate::error::{Error, from_body, Result};
 use crate::forum::Topic;
+use crate::franchise::{Franchise, FranchiseQuery, WalkEntry};
+use crate::id::AnimeId;
 use crate::news::News;
 use crate::pictures::Picture;
 use crate::recommendations::Recommendation;
+use crate::request::HttpClient;
 use crate::reviews::{AnimeReviewer, Review, Reviews};
+use crate::search::enums::{AnimeSource, AnimeStatus, AnimeType, Rating};
+use crate::season::Season;
 use crate::stats::{AnimeStats, Stats};
 use crate::user_updates::{AnimeUserUpdate, UserUpdates};
+use crate::watch_order::{WatchOrder, WatchOrderQuery};
 
 pub mod episodes;
 pub mod videos;
 pub mod characters;
+pub mod resources;
+pub mod airing;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_anime(mal_id: u32, http_clt: &Client) -> Result<Anime> {
-    let url = format!("{}/anime/{}", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let mut anime: Anime = serde_json::from_str(&body)?;
+pub(crate) async fn find_anime(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Anime> {
+    let url = format!("{}/anime/{}", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let mut anime: Anime = from_body(&body)?;
     anime.client = http_clt.clone();
 
     Ok(anime)
@@ -35,8 +42,8 @@ pub(crate) async fn find_anime(mal_id: u32, http_clt: &Client) -> Result<Anime>
 jikan_response_entity!(
     pub struct Anime {
         #[serde(skip)]
-        client: Client,
-        pub mal_id: u32,
+        client: HttpClient,
+        pub mal_id: AnimeId,
         pub url: String,
         pub image_url: Option<String>,
         pub trailer_url: Option<String>,
@@ -45,14 +52,14 @@ jikan_response_entity!(
         pub title_japanese: Option<String>,
         pub title_synonyms: Vec<String>,
         #[serde(rename = "type")]
-        pub anime_type: String,
-        pub source: String,
+        pub anime_type: AnimeType,
+        pub source: AnimeSource,
         pub episodes: Option<u16>,
-        pub status: String,
+        pub status: AnimeStatus,
         pub airing: bool,
         pub aired: Aired,
         pub duration: Option<String>,
-        pub rating: Option<String>,
+        pub rating: Option<Rating>,
         pub score: Option<f32>,
         pub scored_by: Option<u32>,
         pub rank: Option<u32>,
@@ -73,17 +80,70 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Aired {
-    pub from: Option<String>,
-    pub to: Option<String>,
+    pub from: Option<DateTime>,
+    pub to: Option<DateTime>,
 }
 
 impl Anime {
+    /// Gathers the franchise of the anime, see `Jikan::find_franchise`.
+    pub async fn get_franchise(&self, query: FranchiseQuery) -> Result<Franchise> {
+        let seed_entry = WalkEntry::Anime(Box::new(self.clone()));
+        franchise::find_franchise(EntityRef::Anime(self.mal_id), Some(seed_entry), query, &self.client).await
+    }
+
+    /// Gets the order to watch the series of the anime in, see `Jikan::find_watch_order`.
+    pub async fn get_watch_order(&self, query: WatchOrderQuery) -> Result<WatchOrder> {
+        let seed_entry = WalkEntry::Anime(Box::new(self.clone()));
+        watch_order::find_watch_order(self.mal_id, Some(seed_entry), query, &self.client).await
+    }
+
+    /// Parses `broadcast`, e.g. "Saturdays at 01:00 (JST)".
+    pub fn broadcast_time(&self) -> Option<Broadcast> {
+        self.broadcast.as_deref().and_then(airing::parse_broadcast)
+    }
+
+    /// Parses `duration`, e.g. "24 min per ep".
+    pub fn episode_duration(&self) -> Option<Duration> {
+        self.duration.as_deref().and_then(airing::parse_duration)
+    }
+
+    /// Returns the runtime of every episode together, if their number is known.
+    pub fn total_duration(&self) -> Option<Duration> {
+        self.episode_duration()?.checked_mul(u32::from(self.episodes?))
+    }
+
+    /// Parses `premiered`, e.g. "Spring 1998".
+    pub fn premiered_season(&self) -> Option<Season> {
+        self.premiered.as_deref().and_then(airing::parse_premiered)
+    }
+
     pub async fn get_characters(&self) -> Result<CharactersStaff> {
         characters::find_characters(self.mal_id, &self.client).await
     }
 
+    pub async fn get_staff(&self) -> Result<Vec<StaffMember>> {
+        let characters_staff = characters::find_characters(self.mal_id, &self.client).await?;
+        Ok(characters_staff.staff)
+    }
+
+    pub async fn get_relations(&self) -> Result<Vec<Relation>> {
+        resources::find_relations(self.mal_id, &self.client).await
+    }
+
+    pub async fn get_themes(&self) -> Result<Themes> {
+        resources::find_themes(self.mal_id, &self.client).await
+    }
+
+    pub async fn get_external_links(&self) -> Result<Vec<ExternalLink>> {
+        resources::find_external_links(self.mal_id, &self.client).await
+    }
+
+    pub async fn get_streaming(&self) -> Result<Vec<ExternalLink>> {
+        resources::find_streaming(self.mal_id, &self.client).await
+    }
+
     pub async fn get_episodes(&self) -> Result<Vec<EpisodeInfo>> {
         episodes::find_anime_episodes(self.mal_id, &self.client).await
     }
@@ -104,7 +164,7 @@ impl Anime {
         let stats = stats::find_stats(SourceType::Anime(self.mal_id), &self.client).await?;
         match stats {
             Stats::Anime(stats) => Ok(stats),
-            Stats::Manga(_) => Err(Box::from("Expected Anime Stats, but returned Manga Stats")),
+            Stats::Manga(_) => Err(Error::UnexpectedResult { expected: "Anime Stats", returned: "Manga Stats" }),
         }
     }
 
@@ -120,7 +180,7 @@ impl Anime {
         let reviews = reviews::find_reviews(SourceType::Anime(self.mal_id), page, &self.client).await?;
         match reviews {
             Reviews::Anime(reviews) => Ok(reviews),
-            Reviews::Manga(_) => Err(Box::from("Expected Anime Reviews, but returned Manga Reviews")),
+            Reviews::Manga(_) => Err(Error::UnexpectedResult { expected: "Anime Reviews", returned: "Manga Reviews" }),
         }
     }
 
@@ -132,7 +192,7 @@ impl Anime {
         let user_updates = user_updates::find_user_updates(SourceType::Anime(self.mal_id), page, &self.client).await?;
         match user_updates {
             UserUpdates::Anime(user_updates) => Ok(user_updates),
-            UserUpdates::Manga(_) => Err(Box::from("Expected Anime User Updates, but returned Manga User Updates")),
+            UserUpdates::Manga(_) => Err(Error::UnexpectedResult { expected: "Anime User Updates", returned: "Manga User Updates" }),
         }
     }
 }
\ No newline at end of file
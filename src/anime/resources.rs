@@ -0,0 +1,45 @@
+use crate::base::Relation;
+use crate::error::Result;
+use crate::id::AnimeId;
+use crate::request::HttpClient;
+use crate::v4;
+
+// Jikan v3 has no endpoint for these sections, they are fetched from v4 through the same client
+
+pub(crate) async fn find_relations(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Vec<Relation>> {
+    let http_clt = http_clt.v4();
+    let url = format!("{}/anime/{}/relations", http_clt.base_url(), mal_id);
+    v4::get(&url, &http_clt).await
+}
+
+pub(crate) async fn find_themes(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Themes> {
+    let http_clt = http_clt.v4();
+    let url = format!("{}/anime/{}/themes", http_clt.base_url(), mal_id);
+    v4::get(&url, &http_clt).await
+}
+
+pub(crate) async fn find_external_links(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Vec<ExternalLink>> {
+    let http_clt = http_clt.v4();
+    let url = format!("{}/anime/{}/external", http_clt.base_url(), mal_id);
+    v4::get(&url, &http_clt).await
+}
+
+pub(crate) async fn find_streaming(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Vec<ExternalLink>> {
+    let http_clt = http_clt.v4();
+    let url = format!("{}/anime/{}/streaming", http_clt.base_url(), mal_id);
+    v4::get(&url, &http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Themes {
+    #[serde(default)]
+    pub openings: Vec<String>,
+    #[serde(default)]
+    pub endings: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExternalLink {
+    pub name: String,
+    pub url: String,
+}
@@ -1,16 +1,11 @@
-use reqwest::Client;
+use crate::error::{from_body, Result};
+use crate::id::AnimeId;
+use crate::request::{self, HttpClient};
 
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_videos(mal_id: u32, http_clt: &Client) -> Result<Videos> {
-    let url = format!("{}/anime/{}/videos", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let videos: Videos = serde_json::from_str(&body)?;
+pub(crate) async fn find_videos(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Videos> {
+    let url = format!("{}/anime/{}/videos", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let videos: Videos = from_body(&body)?;
 
     Ok(videos)
 }
@@ -22,14 +17,14 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PromoVideo {
     pub title: Option<String>,
     pub image_url: Option<String>,
     pub video_url: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct EpisodeVideo {
     pub title: Option<String>,
     pub episode: Option<String>,
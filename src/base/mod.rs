@@ -1,15 +1,28 @@
+#[cfg(feature = "chrono")]
+use std::convert::TryFrom;
 use std::fmt;
+use std::str::FromStr;
+
 use percent_encoding::{CONTROLS, AsciiSet};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeMap;
+
+use crate::datetime::DateTime;
+use crate::error::{Error, Result};
+use crate::id::{AnimeId, CharacterId, MangaId, PersonId};
+use crate::resolve::Reference;
+use crate::search::enums::{AnimeSource, AnimeType, MangaType};
 
 pub trait Resource {
     fn uri(&self) -> String;
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum SourceType {
-    Anime(u32),
-    Manga(u32),
-    Person(u32),
-    Character(u32),
+    Anime(AnimeId),
+    Manga(MangaId),
+    Person(PersonId),
+    Character(CharacterId),
 }
 
 impl Resource for SourceType {
@@ -40,16 +53,42 @@ pub(crate) const FRAGMENT: &AsciiSet = &CONTROLS
     .add(b',')
     .add(b'&');
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MALTypeItem {
     pub mal_id: u32,
     pub name: String,
     pub url: String,
     #[serde(rename = "type")]
-    pub content_type: String,
+    pub content_type: ContentType,
+}
+
+impl MALTypeItem {
+    /// Returns the anime or manga a related entry refers to. Only relations can be read by their
+    /// content type, other items such as studios and genres carry the type of what they list.
+    pub(crate) fn entity_ref(&self) -> Option<EntityRef> {
+        match self.content_type {
+            ContentType::Anime => Some(EntityRef::Anime(AnimeId(self.mal_id))),
+            ContentType::Manga => Some(EntityRef::Manga(MangaId(self.mal_id))),
+            _ => None,
+        }
+    }
+
+    /// Returns the typed id of the anime, manga, person or character the item links to.
+    ///
+    /// The id is read from the url, as studios, producers and genres share the content type of
+    /// the anime and manga they list.
+    pub fn source(&self) -> Option<SourceType> {
+        match Reference::from_url(&self.url)? {
+            Reference::Anime(id) => Some(SourceType::Anime(id)),
+            Reference::Manga(id) => Some(SourceType::Manga(id)),
+            Reference::Person(id) => Some(SourceType::Person(id)),
+            Reference::Character(id) => Some(SourceType::Character(id)),
+            Reference::Producer(_) | Reference::Magazine(_) => None,
+        }
+    }
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MALImageItem {
     pub mal_id: u32,
     pub name: String,
@@ -57,7 +96,7 @@ pub struct MALImageItem {
     pub image_url: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MALRoleItem {
     pub mal_id: u32,
     pub name: String,
@@ -66,7 +105,7 @@ pub struct MALRoleItem {
     pub role: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VoiceActor {
     pub mal_id: u32,
     pub name: String,
@@ -75,7 +114,7 @@ pub struct VoiceActor {
     pub language: String,
 }
 
-#[derive(Deserialize, Debug, Resource)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Resource)]
 pub enum AnimeStatusForUser {
     #[rename_uri = ""]
     All,
@@ -90,7 +129,7 @@ pub enum AnimeStatusForUser {
     PlanToWatch,
 }
 
-#[derive(Deserialize, Debug, Resource)]
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, Resource)]
 pub enum MangaStatusForUser {
     #[rename_uri = ""]
     All,
@@ -105,52 +144,203 @@ pub enum MangaStatusForUser {
     PlanToRead,
 }
 
-#[derive(Deserialize, Debug)]
+/// Entries related to an anime or manga, grouped by kind of relation in the order MyAnimeList lists them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
 pub struct RelatedContent {
-    #[serde(rename = "Alternative Version", default = "default_content")]
-    pub alternative_versions: Vec<MALTypeItem>,
-    #[serde(rename = "Alternative Setting", default = "default_content")]
-    pub alternative_settings: Vec<MALTypeItem>,
-    #[serde(rename = "Adaptation", default = "default_content")]
-    pub adaptations: Vec<MALTypeItem>,
-    #[serde(rename = "Character", default = "default_content")]
-    pub characters: Vec<MALTypeItem>,
-    #[serde(rename = "Full story", default = "default_content")]
-    pub full_stories: Vec<MALTypeItem>,
-    #[serde(rename = "Parent story", default = "default_content")]
-    pub parent_stories: Vec<MALTypeItem>,
-    #[serde(rename = "Prequel", default = "default_content")]
-    pub prequels: Vec<MALTypeItem>,
-    #[serde(rename = "Sequel", default = "default_content")]
-    pub sequels: Vec<MALTypeItem>,
-    #[serde(rename = "Other", default = "default_content")]
-    pub others: Vec<MALTypeItem>,
-    #[serde(rename = "Side story", default = "default_content")]
-    pub side_stories: Vec<MALTypeItem>,
-    #[serde(rename = "Spin-off", default = "default_content")]
-    pub spin_offs: Vec<MALTypeItem>,
-    #[serde(rename = "Summary", default = "default_content")]
-    pub summaries: Vec<MALTypeItem>,
-}
-
-fn default_content() -> Vec<MALTypeItem> {
-    Vec::with_capacity(0)
-}
-
-#[derive(Deserialize, Debug)]
+    relations: Vec<Relation>,
+}
+
+impl RelatedContent {
+    pub fn relations(&self) -> &[Relation] {
+        &self.relations
+    }
+
+    /// Iterates over every related entry along with its kind of relation.
+    pub fn iter(&self) -> impl Iterator<Item = (&RelationKind, &MALTypeItem)> {
+        self.relations.iter()
+            .flat_map(|relation| relation.entries.iter().map(move |entry| (&relation.relation, entry)))
+    }
+
+    /// Iterates over the anime and manga related to the entry, along with their kind of relation.
+    pub fn entities(&self) -> impl Iterator<Item = (&RelationKind, EntityRef)> {
+        self.iter().filter_map(|(kind, entry)| Some((kind, entry.entity_ref()?)))
+    }
+
+    /// Iterates over the anime and manga related to the entry by the given kind of relation.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::RelationKind;
+    ///
+    /// # async fn prequels() {
+    /// let anime = Jikan::new().find_anime(5).await.unwrap();
+    /// for prequel in anime.related.of_kind(RelationKind::Prequel) {
+    ///     println!("{:?}", prequel);
+    /// }
+    /// # }
+    /// ```
+    pub fn of_kind(&self, kind: RelationKind) -> impl Iterator<Item = EntityRef> + '_ {
+        self.entities().filter(move |(relation, _)| **relation == kind).map(|(_, entity)| entity)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.relations.iter().all(|relation| relation.entries.is_empty())
+    }
+}
+
+impl<'de> Deserialize<'de> for RelatedContent {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct RelatedContentVisitor;
+
+        impl<'de> de::Visitor<'de> for RelatedContentVisitor {
+            type Value = RelatedContent;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map of relations to entries")
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> std::result::Result<Self::Value, A::Error> {
+                let mut relations = Vec::new();
+                while let Some((relation, entries)) = map.next_entry()? {
+                    relations.push(Relation { relation, entries });
+                }
+                Ok(RelatedContent { relations })
+            }
+
+            // Jikan sends an empty array instead of an empty map for entries without relations
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error> {
+                let mut relations = Vec::new();
+                while let Some(relation) = seq.next_element()? {
+                    relations.push(relation);
+                }
+                Ok(RelatedContent { relations })
+            }
+        }
+
+        deserializer.deserialize_any(RelatedContentVisitor)
+    }
+}
+
+impl Serialize for RelatedContent {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.relations.len()))?;
+        for relation in &self.relations {
+            map.serialize_entry(&relation.relation, &relation.entries)?;
+        }
+        map.end()
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Relation {
+    pub relation: RelationKind,
+    #[serde(rename = "entry")]
+    pub entries: Vec<MALTypeItem>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(from = "String", into = "String")]
+pub enum RelationKind {
+    AlternativeVersion,
+    AlternativeSetting,
+    Adaptation,
+    Character,
+    FullStory,
+    ParentStory,
+    Prequel,
+    Sequel,
+    Other,
+    SideStory,
+    SpinOff,
+    Summary,
+    Unknown(String),
+}
+
+// Jikan v4 capitalizes every word of the relation
+jikan_enum_strings!(RelationKind {
+    AlternativeVersion => "Alternative Version" | "Alternative version",
+    AlternativeSetting => "Alternative Setting" | "Alternative setting",
+    Adaptation => "Adaptation",
+    Character => "Character",
+    FullStory => "Full story" | "Full Story",
+    ParentStory => "Parent story" | "Parent Story",
+    Prequel => "Prequel",
+    Sequel => "Sequel",
+    Other => "Other",
+    SideStory => "Side story" | "Side Story",
+    SpinOff => "Spin-off" | "Spin-Off",
+    Summary => "Summary",
+});
+
+/// Anime or manga an item refers to.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EntityRef {
+    Anime(AnimeId),
+    Manga(MangaId),
+}
+
+impl EntityRef {
+    pub fn mal_id(&self) -> u32 {
+        match self {
+            EntityRef::Anime(AnimeId(mal_id)) | EntityRef::Manga(MangaId(mal_id)) => *mal_id,
+        }
+    }
+}
+
+impl From<AnimeId> for EntityRef {
+    fn from(mal_id: AnimeId) -> Self {
+        EntityRef::Anime(mal_id)
+    }
+}
+
+impl From<MangaId> for EntityRef {
+    fn from(mal_id: MangaId) -> Self {
+        EntityRef::Manga(mal_id)
+    }
+}
+
+impl From<EntityRef> for SourceType {
+    fn from(entity: EntityRef) -> Self {
+        match entity {
+            EntityRef::Anime(mal_id) => SourceType::Anime(mal_id),
+            EntityRef::Manga(mal_id) => SourceType::Manga(mal_id),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum ContentType {
+    Anime,
+    Manga,
+    Person,
+    Character,
+    Unknown(String),
+}
+
+jikan_enum_strings!(ContentType {
+    Anime => "anime",
+    Manga => "manga",
+    Person => "people" | "person",
+    Character => "character",
+});
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct AnimeInfo {
-    pub mal_id: u32,
+    pub mal_id: AnimeId,
     pub url: String,
     pub title: String,
     pub image_url: Option<String>,
     pub synopsis: String,
     #[serde(rename = "type")]
-    pub anime_type: String,
-    pub airing_start: Option<String>,
+    pub anime_type: AnimeType,
+    pub airing_start: Option<DateTime>,
     pub episodes: Option<u16>,
     pub members: Option<u32>,
     pub genres: Vec<MALTypeItem>,
-    pub source: String,
+    pub source: AnimeSource,
     pub producers: Vec<MALTypeItem>,
     pub score: Option<f32>,
     pub licensors: Vec<String>,
@@ -160,16 +350,16 @@ pub struct AnimeInfo {
     pub continuing: bool,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct MangaInfo {
-    pub mal_id: u32,
+    pub mal_id: MangaId,
     pub url: String,
     pub title: String,
     pub image_url: Option<String>,
     pub synopsis: String,
     #[serde(rename = "type")]
-    pub manga_type: String,
-    pub publishing_start: Option<String>,
+    pub manga_type: MangaType,
+    pub publishing_start: Option<DateTime>,
     pub volumes: Option<u16>,
     pub members: Option<u32>,
     pub genres: Vec<MALTypeItem>,
@@ -178,24 +368,123 @@ pub struct MangaInfo {
     pub serialization: Vec<String>,
 }
 
+/// Date filter of search and list queries.
+///
+/// Jikan accepts a full date, a year and month, or a year alone. Dates are validated when built.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Date {
     year: u16,
-    month: u8,
-    day: u8,
+    month: Option<u8>,
+    day: Option<u8>,
 }
 
 impl Date {
-    pub fn new(year: u16, month: u8, day: u8) -> Date {
-        Date {
-            year,
-            month,
-            day
+    /// # Examples
+    ///
+    /// ```
+    /// use jikan_rs::prelude::Date;
+    ///
+    /// assert_eq!(Date::new(2020, 1, 5).unwrap().to_string(), "2020-01-05");
+    /// assert!(Date::new(2021, 2, 29).is_err());
+    /// ```
+    pub fn new(year: u16, month: u8, day: u8) -> Result<Date> {
+        let date = Date::from_year_month(year, month)?;
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(Error::InvalidDate(format!("{:04}-{:02}-{:02}", year, month, day)));
+        }
+        Ok(Date { day: Some(day), ..date })
+    }
+
+    pub fn from_year_month(year: u16, month: u8) -> Result<Date> {
+        let date = Date::from_year(year)?;
+        if month == 0 || month > 12 {
+            return Err(Error::InvalidDate(format!("{:04}-{:02}", year, month)));
         }
+        Ok(Date { month: Some(month), ..date })
+    }
+
+    pub fn from_year(year: u16) -> Result<Date> {
+        if year == 0 || year > 9999 {
+            return Err(Error::InvalidDate(year.to_string()));
+        }
+        Ok(Date { year, month: None, day: None })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
     }
 }
 
 impl fmt::Display for Date {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}-{}-{}", self.year, self.month, self.day)
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+impl FromStr for Date {
+    type Err = Error;
+
+    /// Parses `YYYY`, `YYYY-MM` or `YYYY-MM-DD`.
+    fn from_str(value: &str) -> Result<Date> {
+        let invalid = || Error::InvalidDate(value.to_string());
+        let parts = value.split('-')
+            .map(|part| match part.len() {
+                1..=4 if part.bytes().all(|b| b.is_ascii_digit()) => part.parse::<u16>().ok(),
+                _ => None,
+            })
+            .collect::<Option<Vec<u16>>>()
+            .ok_or_else(invalid)?;
+        let date = match *parts.as_slice() {
+            [year] => Date::from_year(year),
+            [year, month] if month <= 12 => Date::from_year_month(year, month as u8),
+            [year, month, day] if month <= 12 && day <= 31 => Date::new(year, month as u8, day as u8),
+            _ => Err(invalid()),
+        };
+        date.map_err(|_| invalid())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for Date {
+    type Error = Error;
+
+    fn try_from(date: chrono::NaiveDate) -> Result<Date> {
+        use chrono::Datelike;
+
+        let year = u16::try_from(date.year()).map_err(|_| Error::InvalidDate(date.to_string()))?;
+        Date::new(year, date.month() as u8, date.day() as u8)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::DateTime<chrono::FixedOffset>> for Date {
+    type Error = Error;
+
+    fn try_from(date: chrono::DateTime<chrono::FixedOffset>) -> Result<Date> {
+        Date::try_from(date.date_naive())
+    }
+}
@@ -0,0 +1,184 @@
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, UNIX_EPOCH};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+
+use crate::cache::{Cache, CacheEntry, CacheStats};
+use crate::error::Result;
+
+const EXTENSION: &str = "json.gz";
+
+/// Writes made by every cache of the process, numbering their temporary files.
+static WRITES: AtomicU64 = AtomicU64::new(0);
+
+/// Cache persisting every entry as a gzip compressed JSON file inside a directory.
+///
+/// Entries survive restarts, so several runs of a batch job share what was already fetched.
+/// Failing to read or write an entry is treated as a cache miss and never fails a request.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use jikan_rs::cache::{Cache, DiskCache};
+/// use jikan_rs::client::Jikan;
+///
+/// let cache = Arc::new(DiskCache::open("/var/cache/jikan").unwrap());
+/// let jikan = Jikan::new().with_cache(cache.clone());
+///
+/// // Later on, e.g. at the end of a batch
+/// let pruned = cache.prune_expired().unwrap();
+/// println!("{} expired entries removed, hit rate {}", pruned, cache.stats().hit_rate());
+/// ```
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    request_hash: Option<String>,
+    expires_at: u64,
+    body: String,
+}
+
+impl DiskCache {
+    /// Opens the cache stored in `dir`, creating the directory if needed.
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<DiskCache> {
+        fs::create_dir_all(dir.as_ref())?;
+        Ok(DiskCache {
+            dir: dir.as_ref().to_path_buf(),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Removes expired and unreadable entries, returning how many were removed.
+    pub fn prune_expired(&self) -> Result<usize> {
+        let mut pruned = 0;
+        for path in self.entry_paths()? {
+            let expired = match read_entry(&path) {
+                Some(entry) => to_cache_entry(entry).is_expired(),
+                None => true,
+            };
+            if expired {
+                match fs::remove_file(&path) {
+                    Ok(()) => pruned += 1,
+                    // Removed meanwhile by `remove` or `clear`
+                    Err(err) if err.kind() == io::ErrorKind::NotFound => (),
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+        Ok(pruned)
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{:016x}.{}", fnv1a(key), EXTENSION))
+    }
+
+    fn entry_paths(&self) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let path = dir_entry?.path();
+            if path.to_string_lossy().ends_with(EXTENSION) {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    fn write(&self, key: &str, entry: CacheEntry) -> Result<()> {
+        let entry = DiskEntry {
+            key: key.to_string(),
+            request_hash: entry.request_hash,
+            expires_at: entry.expires_at.duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0),
+            body: entry.body,
+        };
+        let path = self.path(key);
+        // Concurrent writers of the same key each fill their own file, the last rename wins
+        let write = WRITES.fetch_add(1, Ordering::Relaxed);
+        let tmp_path = path.with_extension(format!("{}.{}.tmp", std::process::id(), write));
+        let written = write_entry(&tmp_path, &entry).and_then(|_| fs::rename(&tmp_path, path));
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp_path);
+        }
+        Ok(written?)
+    }
+}
+
+impl Cache for DiskCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let entry = read_entry(&self.path(key))
+            .filter(|entry| entry.key == key)
+            .map(to_cache_entry)
+            .filter(|entry| !entry.is_expired());
+        match entry {
+            Some(_) => self.hits.fetch_add(1, Ordering::Relaxed),
+            None => self.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        entry
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        let _ = self.write(key, entry);
+    }
+
+    fn remove(&self, key: &str) {
+        let _ = fs::remove_file(self.path(key));
+    }
+
+    fn clear(&self) {
+        if let Ok(paths) = self.entry_paths() {
+            for path in paths {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+
+    fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self.entry_paths().map(|paths| paths.len()).unwrap_or(0),
+        }
+    }
+}
+
+fn write_entry(path: &Path, entry: &DiskEntry) -> io::Result<()> {
+    let mut encoder = GzEncoder::new(File::create(path)?, Compression::default());
+    serde_json::to_writer(&mut encoder, entry).map_err(io::Error::from)?;
+    encoder.finish()?.sync_all()
+}
+
+fn read_entry(path: &Path) -> Option<DiskEntry> {
+    let mut json = Vec::new();
+    GzDecoder::new(File::open(path).ok()?).read_to_end(&mut json).ok()?;
+    serde_json::from_slice(&json).ok()
+}
+
+fn to_cache_entry(entry: DiskEntry) -> CacheEntry {
+    CacheEntry {
+        body: entry.body,
+        request_hash: entry.request_hash,
+        expires_at: UNIX_EPOCH + Duration::from_secs(entry.expires_at),
+    }
+}
+
+fn fnv1a(key: &str) -> u64 {
+    key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
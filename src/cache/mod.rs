@@ -0,0 +1,210 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+pub use self::disk::DiskCache;
+
+mod disk;
+
+/// Storage for raw response bodies, keyed by request URL.
+///
+/// Implementations must be safe to share between every clone of a `Jikan` client.
+pub trait Cache: Send + Sync {
+    /// Returns the entry stored for `key`, if it has not expired.
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+
+    /// Stores `entry` for `key`, replacing any previous entry.
+    fn put(&self, key: &str, entry: CacheEntry);
+
+    /// Removes the entry stored for `key`, if any.
+    fn remove(&self, key: &str);
+
+    /// Removes every entry.
+    fn clear(&self);
+
+    /// Hits and misses counted since the cache was created.
+    fn stats(&self) -> CacheStats {
+        CacheStats::default()
+    }
+}
+
+/// Lookup counters reported by a cache.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+impl CacheStats {
+    /// Ratio of lookups served from the cache, between 0 and 1.
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// A response body along with the caching information sent by Jikan.
+#[derive(Clone, Debug)]
+pub struct CacheEntry {
+    pub body: String,
+    pub request_hash: Option<String>,
+    pub expires_at: SystemTime,
+}
+
+#[derive(Deserialize)]
+struct CacheInfo {
+    request_hash: Option<String>,
+    request_cache_expiry: Option<u64>,
+}
+
+impl CacheEntry {
+    /// Builds an entry expiring after the `request_cache_expiry` seconds found in `body`.
+    ///
+    /// Returns `None` when the body carries no expiry, such responses are never cached.
+    pub fn from_body(body: &str) -> Option<CacheEntry> {
+        let info: CacheInfo = serde_json::from_str(body).ok()?;
+        let expiry = info.request_cache_expiry?;
+        Some(CacheEntry {
+            body: body.to_string(),
+            request_hash: info.request_hash,
+            expires_at: SystemTime::now() + Duration::from_secs(expiry),
+        })
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= SystemTime::now()
+    }
+}
+
+/// In-memory cache evicting the least recently used entry once `capacity` is reached.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use jikan_rs::cache::MemoryCache;
+/// use jikan_rs::client::Jikan;
+///
+/// let jikan = Jikan::new().with_cache(Arc::new(MemoryCache::new(500)));
+/// ```
+pub struct MemoryCache {
+    capacity: usize,
+    inner: Mutex<MemoryCacheInner>,
+}
+
+#[derive(Default)]
+struct MemoryCacheInner {
+    entries: HashMap<String, (u64, CacheEntry)>,
+    /// Keys by the tick they were last used at, the least recently used first.
+    recency: BTreeMap<u64, String>,
+    tick: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl MemoryCache {
+    pub fn new(capacity: usize) -> MemoryCache {
+        MemoryCache {
+            capacity,
+            inner: Mutex::new(MemoryCacheInner::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl MemoryCacheInner {
+    /// Marks the entry stored for `key` as the most recently used one.
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some((last_used, _)) = self.entries.get_mut(key) {
+            let previous = std::mem::replace(last_used, tick);
+            if let Some(key) = self.recency.remove(&previous) {
+                self.recency.insert(tick, key);
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some((last_used, _)) = self.entries.remove(key) {
+            self.recency.remove(&last_used);
+        }
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let mut inner = self.inner.lock().unwrap();
+        let entry = match inner.entries.get(key) {
+            Some((_, entry)) if entry.is_expired() => None,
+            Some((_, entry)) => Some(entry.clone()),
+            None => None,
+        };
+        if entry.is_some() {
+            inner.touch(key);
+        }
+        match entry {
+            Some(_) => inner.hits += 1,
+            None => inner.misses += 1,
+        }
+        entry
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut inner = self.inner.lock().unwrap();
+        inner.remove(key);
+        if inner.entries.len() >= self.capacity {
+            if let Some((_, least_recently_used)) = inner.recency.pop_first() {
+                inner.entries.remove(&least_recently_used);
+            }
+        }
+        inner.tick += 1;
+        let tick = inner.tick;
+        inner.recency.insert(tick, key.to_string());
+        inner.entries.insert(key.to_string(), (tick, entry));
+    }
+
+    fn remove(&self, key: &str) {
+        self.inner.lock().unwrap().remove(key);
+    }
+
+    fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.clear();
+        inner.recency.clear();
+    }
+
+    fn stats(&self) -> CacheStats {
+        let inner = self.inner.lock().unwrap();
+        CacheStats {
+            hits: inner.hits,
+            misses: inner.misses,
+            entries: inner.entries.len(),
+        }
+    }
+}
+
+impl fmt::Debug for MemoryCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryCache")
+            .field("capacity", &self.capacity)
+            .field("len", &self.len())
+            .finish()
+    }
+}
@@ -1,19 +1,14 @@
-use reqwest::Client;
-
 use crate::base::{MALRoleItem, SourceType, VoiceActor};
-use crate::client::BASE_URL;
+use crate::error::{from_body, Result};
+use crate::id::CharacterId;
 use crate::pictures;
 use crate::pictures::Picture;
+use crate::request::{self, HttpClient};
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_character(mal_id: u32, http_clt: &Client) -> Result<Character> {
-    let url = format!("{}/character/{}", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let mut character: Character = serde_json::from_str(&body)?;
+pub(crate) async fn find_character(mal_id: CharacterId, http_clt: &HttpClient) -> Result<Character> {
+    let url = format!("{}/character/{}", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let mut character: Character = from_body(&body)?;
 
     character.client = http_clt.clone();
 
@@ -21,10 +16,11 @@ pub(crate) async fn find_character(mal_id: u32, http_clt: &Client) -> Result<Cha
 }
 
 jikan_response_entity!(
+    #[derive(Eq, Hash)]
     pub struct Character {
         #[serde(skip)]
-        client: Client,
-        pub mal_id: u32,
+        client: HttpClient,
+        pub mal_id: CharacterId,
         pub url: String,
         pub image_url: String,
         pub name: String,
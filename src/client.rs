@@ -1,40 +1,135 @@
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::{anime, character, club, forum, genre, magazine, manga, meta, more_info, news, person, pictures,
-            producer, recommendations, reviews, schedule, search, season, stats, top, user, user_updates};
-use crate::anime::{Anime, characters::CharactersStaff, episodes::EpisodeInfo, videos::Videos};
-use crate::base::{MALRoleItem, SourceType};
+use futures::stream::{self, BoxStream, StreamExt};
+use reqwest::{Client, Proxy};
+use reqwest::header::HeaderMap;
+
+use crate::{anime, character, club, forum, franchise, genre, magazine, manga, meta, more_info, news, person,
+            pictures, producer, random, recommendations, resolve, reviews, schedule, search, season, stats, top,
+            user, user_updates, watch_order};
+use crate::anime::{Anime, characters::{CharactersStaff, StaffMember}, episodes::EpisodeInfo, videos::Videos};
+use crate::anime::resources::{ExternalLink, Themes};
+use crate::base::{AnimeInfo, EntityRef, MALRoleItem, MangaInfo, Relation, SourceType};
+use crate::cache::{Cache, CacheStats};
 use crate::character::Character;
 use crate::club::{Club, ClubMember};
+use crate::error::{Error, Result};
 use crate::forum::Topic;
+use crate::franchise::{Franchise, FranchiseQuery};
 use crate::genre::{GenreAnimeResult, GenreMangaResult};
+use crate::id::{AnimeId, CharacterId, ClubId, MagazineId, MangaId, PersonId, ProducerId};
 use crate::magazine::Magazine;
 use crate::manga::Manga;
 use crate::meta::{ApiStatus, InfoAbout, Period};
 use crate::news::News;
 use crate::person::Person;
 use crate::pictures::Picture;
+use crate::pagination::{self, Page};
 use crate::producer::Producer;
+use crate::rate_limit::RateLimiter;
 use crate::recommendations::Recommendation;
-use crate::reviews::Reviews;
+use crate::resolve::{Entity, Reference};
+use crate::request::HttpClient;
+use crate::retry::RetryPolicy;
+use crate::reviews::{Review, Reviews};
 use crate::schedule::{Schedule, ScheduleOn};
-use crate::search::{results::SearchResultEnum, SearchQueryBuilder};
+use crate::search::{results::{SearchResult, SearchResultEnum}, SearchQueryBuilder};
 use crate::search::enums::{AnimeGenre, MangaGenre};
 use crate::season::{Season, SeasonResult};
 use crate::season::archive::ArchivedSeason;
 use crate::stats::Stats;
 use crate::top::{Top, TopResult};
-use crate::user::results::UserResultEnum;
+use crate::transport::{ReqwestTransport, Transport};
+use crate::user::results::{Profile, UserResultEnum};
 use crate::user::UserInfo;
 use crate::user_updates::UserUpdates;
+use crate::v4;
+use crate::watch_order::{WatchOrder, WatchOrderQuery};
 
 pub const BASE_URL: &str = "http://api.jikan.moe/v3";
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+builder!(
+    pub struct JikanBuilder {
+        base_url: String,
+        v4_base_url: String,
+        timeout: Duration,
+        user_agent: String,
+        default_headers: HeaderMap,
+        proxy: Proxy,
+        client: Client,
+        transport: Arc<dyn Transport>,
+        rate_limiter: RateLimiter,
+        retry_policy: RetryPolicy,
+        cache: Arc<dyn Cache>,
+        concurrency: usize,
+        throttle_walks: bool,
+    }
+);
+
+impl JikanBuilder {
+    /// Builds the `Jikan` client.
+    ///
+    /// Timeout, user agent, default headers and proxy only apply to the HTTP client built here,
+    /// they are ignored when a prebuilt `reqwest::Client` or a custom transport is provided.
+    ///
+    /// Concurrency bounds how many pages are fetched at once by calls gathering several pages,
+    /// such as `find_episodes`. It defaults to 4, the rate limiter still applies to every request.
+    ///
+    /// Franchise and watch order walks of a client without rate limiter share a `RateLimiter::default()`
+    /// unless `throttle_walks` is set to false, e.g. for a self-hosted Jikan or a fixture transport.
+    pub fn build(self) -> Result<Jikan> {
+        let transport: Arc<dyn Transport> = match (self.transport, self.client) {
+            (Some(transport), _) => transport,
+            (None, Some(client)) => Arc::new(ReqwestTransport::new(client)),
+            (None, None) => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if let Some(headers) = self.default_headers {
+                    builder = builder.default_headers(headers);
+                }
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                Arc::new(ReqwestTransport::new(builder.build()?))
+            }
+        };
 
-#[derive(Default)]
+        let base_url = self.base_url.as_deref().unwrap_or(BASE_URL);
+        let mut http_client = HttpClient::new(transport, base_url);
+        if let Some(v4_base_url) = self.v4_base_url {
+            http_client = http_client.with_v4_base_url(&v4_base_url);
+        }
+        if let Some(rate_limiter) = self.rate_limiter {
+            http_client = http_client.with_rate_limiter(rate_limiter);
+        }
+        if let Some(retry_policy) = self.retry_policy {
+            http_client = http_client.with_retry_policy(retry_policy);
+        }
+        if let Some(cache) = self.cache {
+            http_client = http_client.with_cache(cache);
+        }
+        if let Some(concurrency) = self.concurrency {
+            http_client = http_client.with_concurrency(concurrency);
+        }
+        if self.throttle_walks == Some(false) {
+            http_client = http_client.without_walk_rate_limiter();
+        }
+
+        Ok(Jikan { http_client })
+    }
+}
+
+#[derive(Default, Clone)]
 pub struct Jikan {
-    http_client: reqwest::Client,
+    http_client: HttpClient,
 }
 
 impl Jikan {
@@ -49,10 +144,155 @@ impl Jikan {
     /// ```
     pub fn new() -> Jikan {
         Jikan {
-            http_client: reqwest::Client::new()
+            http_client: HttpClient::default()
+        }
+    }
+
+    /// Constructs a builder to configure the `Jikan` client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::builder()
+    ///     .base_url(String::from("http://localhost:8000/v3"))
+    ///     .timeout(Duration::from_secs(10))
+    ///     .user_agent(String::from("my-app/1.0"))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> JikanBuilder {
+        JikanBuilder::new()
+    }
+
+    /// Limits the requests made through this client and the entities returned by it.
+    ///
+    /// Clones of the client share the same limiter, so concurrent tasks never exceed the quotas together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::RateLimiter;
+    ///
+    /// // Jikan v3 limits: 2 requests per second and 30 requests per minute
+    /// let jikan = Jikan::new().with_rate_limiter(RateLimiter::default());
+    /// ```
+    pub fn with_rate_limiter(self, rate_limiter: RateLimiter) -> Jikan {
+        Jikan {
+            http_client: self.http_client.with_rate_limiter(rate_limiter)
+        }
+    }
+
+    /// Retries requests failing with transient errors according to the policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::RetryPolicy;
+    ///
+    /// let jikan = Jikan::new().with_retry_policy(RetryPolicy::new());
+    /// ```
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Jikan {
+        Jikan {
+            http_client: self.http_client.with_retry_policy(retry_policy)
+        }
+    }
+
+    /// Serves repeated requests from the cache until Jikan's `request_cache_expiry` elapses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use jikan_rs::cache::MemoryCache;
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new().with_cache(Arc::new(MemoryCache::new(1000)));
+    /// ```
+    pub fn with_cache(self, cache: Arc<dyn Cache>) -> Jikan {
+        Jikan {
+            http_client: self.http_client.with_cache(cache)
+        }
+    }
+
+    /// Returns a client sharing this one's configuration that skips cache lookups.
+    ///
+    /// Responses it fetches still refresh the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use std::sync::Arc;
+    /// use jikan_rs::cache::MemoryCache;
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new().with_cache(Arc::new(MemoryCache::new(1000)));
+    /// let fresh_anime = jikan.bypass_cache()
+    ///     .find_anime(1)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn bypass_cache(&self) -> Jikan {
+        Jikan {
+            http_client: self.http_client.bypassing_cache()
+        }
+    }
+
+    /// Removes the cached response of an endpoint, given its path relative to the base URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use jikan_rs::cache::MemoryCache;
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new().with_cache(Arc::new(MemoryCache::new(1000)));
+    /// jikan.invalidate_cache("/anime/1");
+    /// ```
+    pub fn invalidate_cache(&self, path: &str) {
+        if let Some(cache) = self.http_client.cache() {
+            cache.remove(&format!("{}{}", self.http_client.base_url(), path));
         }
     }
 
+    /// Removes every cached response.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = self.http_client.cache() {
+            cache.clear();
+        }
+    }
+
+    /// Hits and misses of the attached cache, if any.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.http_client.cache().map(|cache| cache.stats())
+    }
+
+    /// Returns a v4 client sharing the transport, rate limiter, retry policy and cache of this one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// let anime = jikan.v4()
+    ///     .find_anime(1)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn v4(&self) -> v4::Jikan {
+        v4::Jikan::from_http_client(self.http_client.v4())
+    }
+
     /// Get the anime providing its MAL id.
     ///
     /// # Examples
@@ -68,8 +308,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_anime(&self, mal_id: u32) -> Result<Anime> {
-        anime::find_anime(mal_id, &self.http_client).await
+    pub async fn find_anime(&self, mal_id: impl Into<AnimeId>) -> Result<Anime> {
+        anime::find_anime(mal_id.into(), &self.http_client).await
     }
 
     /// Get the manga providing its MAL id.
@@ -87,8 +327,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_manga(&self, mal_id: u32) -> Result<Manga> {
-        manga::find_manga(mal_id, &self.http_client).await
+    pub async fn find_manga(&self, mal_id: impl Into<MangaId>) -> Result<Manga> {
+        manga::find_manga(mal_id.into(), &self.http_client).await
     }
 
     /// Get the person info providing its MAL id.
@@ -106,8 +346,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_person(&self, mal_id: u32) -> Result<Person> {
-        person::find_person(mal_id, &self.http_client).await
+    pub async fn find_person(&self, mal_id: impl Into<PersonId>) -> Result<Person> {
+        person::find_person(mal_id.into(), &self.http_client).await
     }
 
     /// Get the character providing its MAL id.
@@ -125,8 +365,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_character(&self, mal_id: u32) -> Result<Character> {
-        character::find_character(mal_id, &self.http_client).await
+    pub async fn find_character(&self, mal_id: impl Into<CharacterId>) -> Result<Character> {
+        character::find_character(mal_id.into(), &self.http_client).await
     }
 
     /// Get all characters of the anime.
@@ -144,8 +384,64 @@ impl Jikan {
     ///     .unwrap();
     /// }
     /// ```
-    pub async fn find_anime_characters(&self, mal_id: u32) -> Result<CharactersStaff> {
-        anime::characters::find_characters(mal_id, &self.http_client).await
+    pub async fn find_anime_characters(&self, mal_id: impl Into<AnimeId>) -> Result<CharactersStaff> {
+        anime::characters::find_characters(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the staff of the anime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// // Returns the staff of Cowboy Bebop
+    /// let staff = jikan.find_anime_staff(1)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn find_anime_staff(&self, mal_id: impl Into<AnimeId>) -> Result<Vec<StaffMember>> {
+        let characters_staff = anime::characters::find_characters(mal_id.into(), &self.http_client).await?;
+        Ok(characters_staff.staff)
+    }
+
+    /// Get the prequels, sequels, adaptations and other entries related to the anime.
+    ///
+    /// Jikan v3 has no such endpoint, the relations are fetched from the v4 API.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// // Returns the entries related to Cowboy Bebop
+    /// let relations = jikan.find_anime_relations(1)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn find_anime_relations(&self, mal_id: impl Into<AnimeId>) -> Result<Vec<Relation>> {
+        anime::resources::find_relations(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the opening and ending themes of the anime, fetched from the v4 API.
+    pub async fn find_anime_themes(&self, mal_id: impl Into<AnimeId>) -> Result<Themes> {
+        anime::resources::find_themes(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the official website and other links of the anime, fetched from the v4 API.
+    pub async fn find_anime_external_links(&self, mal_id: impl Into<AnimeId>) -> Result<Vec<ExternalLink>> {
+        anime::resources::find_external_links(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the streaming services offering the anime, fetched from the v4 API.
+    pub async fn find_anime_streaming(&self, mal_id: impl Into<AnimeId>) -> Result<Vec<ExternalLink>> {
+        anime::resources::find_streaming(mal_id.into(), &self.http_client).await
     }
 
     /// Get all characters of the manga.
@@ -163,11 +459,13 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_manga_characters(&self, mal_id: u32) -> Result<Vec<MALRoleItem>> {
-        manga::characters::find_characters(mal_id, &self.http_client).await
+    pub async fn find_manga_characters(&self, mal_id: impl Into<MangaId>) -> Result<Vec<MALRoleItem>> {
+        manga::characters::find_characters(mal_id.into(), &self.http_client).await
     }
 
-    /// Get information about the anime episodes.
+    /// Get information about all the anime episodes.
+    ///
+    /// After the first page, the remaining pages are fetched concurrently, see `JikanBuilder::build`.
     ///
     /// # Examples
     ///
@@ -182,8 +480,28 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_episodes(&self, mal_id: u32) -> Result<Vec<EpisodeInfo>> {
-        anime::episodes::find_anime_episodes(mal_id, &self.http_client).await
+    pub async fn find_episodes(&self, mal_id: impl Into<AnimeId>) -> Result<Vec<EpisodeInfo>> {
+        anime::episodes::find_anime_episodes(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get a single page of the anime episodes, up to 100 episodes per page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// // Returns episodes 101 to 200 of One Piece
+    /// let page = jikan.find_episodes_page(21, 2)
+    ///     .await
+    ///     .unwrap();
+    /// println!("{} pages in total", page.last_page.unwrap());
+    /// # }
+    /// ```
+    pub async fn find_episodes_page(&self, mal_id: impl Into<AnimeId>, page: u16) -> Result<Page<EpisodeInfo>> {
+        anime::episodes::find_episodes_page(mal_id.into(), page, &self.http_client).await
     }
 
     /// Get news of the anime/manga.
@@ -193,11 +511,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::SourceType;
+    /// use jikan_rs::prelude::{AnimeId, SourceType};
     ///
     /// let jikan = Jikan::new();
     /// // Returns Cowboy Bebop news
-    /// let news = jikan.find_news(SourceType::Anime(1))
+    /// let news = jikan.find_news(SourceType::Anime(AnimeId(1)))
     ///     .await
     ///     .unwrap();
     /// # }
@@ -213,11 +531,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::SourceType;
+    /// use jikan_rs::prelude::{MangaId, SourceType};
     ///
     /// let jikan = Jikan::new();
     /// // Returns Monster pictures
-    /// let pictures = jikan.find_pictures(SourceType::Manga(1))
+    /// let pictures = jikan.find_pictures(SourceType::Manga(MangaId(1)))
     ///     .await
     ///     .unwrap();
     /// # }
@@ -241,8 +559,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_videos(&self, mal_id: u32) -> Result<Videos> {
-        anime::videos::find_videos(mal_id, &self.http_client).await
+    pub async fn find_videos(&self, mal_id: impl Into<AnimeId>) -> Result<Videos> {
+        anime::videos::find_videos(mal_id.into(), &self.http_client).await
     }
 
     /// Get statistical information of the anime/manga.
@@ -252,11 +570,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::{SourceType, Stats};
+    /// use jikan_rs::prelude::{AnimeId, SourceType, Stats};
     ///
     /// let jikan = Jikan::new();
     /// // Returns Cowboy Bebop stats
-    /// let stats = jikan.find_stats(SourceType::Anime(1))
+    /// let stats = jikan.find_stats(SourceType::Anime(AnimeId(1)))
     ///     .await
     ///     .unwrap();
     ///
@@ -276,11 +594,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::SourceType;
+    /// use jikan_rs::prelude::{MangaId, SourceType};
     ///
     /// let jikan = Jikan::new();
     /// // Returns Monster forum topics
-    /// let topics = jikan.find_forum(SourceType::Manga(1))
+    /// let topics = jikan.find_forum(SourceType::Manga(MangaId(1)))
     ///     .await
     ///     .unwrap();
     /// # }
@@ -296,11 +614,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::SourceType;
+    /// use jikan_rs::prelude::{AnimeId, SourceType};
     ///
     /// let jikan = Jikan::new();
     /// // Returns more information about Cowboy Bebop
-    /// let info = jikan.find_more_info(SourceType::Anime(1))
+    /// let info = jikan.find_more_info(SourceType::Anime(AnimeId(1)))
     ///     .await
     ///     .unwrap();
     /// # }
@@ -318,11 +636,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::{SourceType, Reviews};
+    /// use jikan_rs::prelude::{AnimeId, Reviews, SourceType};
     ///
     /// let jikan = Jikan::new();
     /// // Returns reviews about Cowboy Bebop
-    /// let reviews = jikan.find_reviews(SourceType::Anime(1), 1)
+    /// let reviews = jikan.find_reviews(SourceType::Anime(AnimeId(1)), 1)
     ///     .await
     ///     .unwrap();
     ///
@@ -342,11 +660,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::SourceType;
+    /// use jikan_rs::prelude::{AnimeId, SourceType};
     ///
     /// let jikan = Jikan::new();
     /// // Returns Cowboy Bebop recommendations
-    /// let recommendations = jikan.find_recommendations(SourceType::Anime(1))
+    /// let recommendations = jikan.find_recommendations(SourceType::Anime(AnimeId(1)))
     ///     .await
     ///     .unwrap();
     /// # }
@@ -364,11 +682,11 @@ impl Jikan {
     /// ```rust,no_run
     /// # async fn run() {
     /// use jikan_rs::client::Jikan;
-    /// use jikan_rs::prelude::{SourceType, UserUpdates};
+    /// use jikan_rs::prelude::{AnimeId, SourceType, UserUpdates};
     ///
     /// let jikan = Jikan::new();
     /// // Returns Cowboy Bebop latest updates
-    /// let last_updates = jikan.find_user_updates(SourceType::Anime(1), 1)
+    /// let last_updates = jikan.find_user_updates(SourceType::Anime(AnimeId(1)), 1)
     ///     .await
     ///     .unwrap();
     ///
@@ -529,8 +847,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_producer(&self, id: u32, page: u16) -> Result<Producer> {
-        producer::find_producer(id, page, &self.http_client).await
+    pub async fn find_producer(&self, id: impl Into<ProducerId>, page: u16) -> Result<Producer> {
+        producer::find_producer(id.into(), page, &self.http_client).await
     }
 
     /// Get the mangas of the magazine.
@@ -550,8 +868,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_magazine(&self, id: u32, page: u16) -> Result<Magazine> {
-        magazine::find_magazine(id, page, &self.http_client).await
+    pub async fn find_magazine(&self, id: impl Into<MagazineId>, page: u16) -> Result<Magazine> {
+        magazine::find_magazine(id.into(), page, &self.http_client).await
     }
 
     /// Get the club providing its MAL id.
@@ -569,8 +887,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_club(&self, mal_id: u32) -> Result<Club> {
-        club::find_club(mal_id, &self.http_client).await
+    pub async fn find_club(&self, mal_id: impl Into<ClubId>) -> Result<Club> {
+        club::find_club(mal_id.into(), &self.http_client).await
     }
 
     /// Get the club members providing its MAL id.
@@ -590,8 +908,8 @@ impl Jikan {
     ///     .unwrap();
     /// # }
     /// ```
-    pub async fn find_club_members(&self, club_id: u32, page: u32) -> Result<Vec<ClubMember>> {
-        club::find_club_members(club_id, page, &self.http_client).await
+    pub async fn find_club_members(&self, club_id: impl Into<ClubId>, page: u16) -> Result<Vec<ClubMember>> {
+        club::find_club_members(club_id.into(), page, &self.http_client).await
     }
 
     /// Get user related data.
@@ -629,6 +947,157 @@ impl Jikan {
         user::find_user(username, user_info, &self.http_client).await
     }
 
+    /// Get a random anime.
+    ///
+    /// The entry is picked by the v4 API, then fetched like `find_anime` does.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// let anime = jikan.random_anime()
+    ///     .await
+    ///     .unwrap();
+    /// let episodes = anime.get_episodes()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn random_anime(&self) -> Result<Anime> {
+        let mal_id = random::find_random_mal_id("anime", &self.http_client).await?;
+        anime::find_anime(AnimeId(mal_id), &self.http_client).await
+    }
+
+    /// Get a random manga, picked by the v4 API.
+    pub async fn random_manga(&self) -> Result<Manga> {
+        let mal_id = random::find_random_mal_id("manga", &self.http_client).await?;
+        manga::find_manga(MangaId(mal_id), &self.http_client).await
+    }
+
+    /// Get a random character, picked by the v4 API.
+    pub async fn random_character(&self) -> Result<Character> {
+        let mal_id = random::find_random_mal_id("characters", &self.http_client).await?;
+        character::find_character(CharacterId(mal_id), &self.http_client).await
+    }
+
+    /// Get a random person, picked by the v4 API.
+    pub async fn random_person(&self) -> Result<Person> {
+        let mal_id = random::find_random_mal_id("people", &self.http_client).await?;
+        person::find_person(PersonId(mal_id), &self.http_client).await
+    }
+
+    /// Get the profile of a random user, picked by the v4 API.
+    pub async fn random_user(&self) -> Result<Profile> {
+        let username = random::find_random_username(&self.http_client).await?;
+        let user_result = user::find_user(&username, UserInfo::Profile, &self.http_client).await?;
+        Profile::try_from(user_result)
+    }
+
+    /// Walk the relations of an anime or manga to gather its whole franchise.
+    ///
+    /// Entries are fetched one after the other, breadth first, each one only once. Prequels,
+    /// sequels, side stories, spin-offs, adaptations and alternative versions are followed unless
+    /// the query says otherwise, and the walk stops after 100 entries by default. The walk obeys
+    /// the rate limiter of the client. When the client has none, its walks share a `RateLimiter::default()`
+    /// unless it was built with `throttle_walks(false)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::{AnimeId, EntityRef, FranchiseQuery};
+    ///
+    /// let jikan = Jikan::new();
+    /// // Returns every entry of the Cowboy Bebop franchise
+    /// let franchise = jikan.find_franchise(EntityRef::Anime(AnimeId(1)), FranchiseQuery::new().max_entries(20))
+    ///     .await
+    ///     .unwrap();
+    /// println!("{}", franchise.to_dot());
+    /// # }
+    /// ```
+    pub async fn find_franchise(&self, seed: EntityRef, query: FranchiseQuery) -> Result<Franchise> {
+        franchise::find_franchise(seed, None, query, &self.http_client).await
+    }
+
+    /// Get the order to watch an anime series in.
+    ///
+    /// The prequels, sequels, side stories and summaries of the anime are walked like
+    /// `find_franchise` does, then ordered by their relations and by the start of their airing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::{AnimeType, WatchOrderQuery};
+    ///
+    /// let jikan = Jikan::new();
+    /// // Returns the Cowboy Bebop series without recaps nor specials
+    /// let query = WatchOrderQuery::new()
+    ///     .exclude_summaries(true)
+    ///     .exclude_types(vec![AnimeType::Special]);
+    /// let watch_order = jikan.find_watch_order(1, query)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn find_watch_order(&self, mal_id: impl Into<AnimeId>, query: WatchOrderQuery) -> Result<WatchOrder> {
+        watch_order::find_watch_order(mal_id.into(), None, query, &self.http_client).await
+    }
+
+    /// Get the entity a reference points to.
+    ///
+    /// Producers and magazines are returned with the first page of their animes or mangas.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::{ProducerId, Reference};
+    ///
+    /// let jikan = Jikan::new();
+    /// // Returns Sunrise
+    /// let entity = jikan.resolve(Reference::Producer(ProducerId(14)))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn resolve(&self, reference: Reference) -> Result<Entity> {
+        resolve::resolve(reference, &self.http_client).await
+    }
+
+    /// Get the entities several references point to, fetching each distinct one once.
+    ///
+    /// Each reference is returned with its result, in the order the references first appear. A
+    /// reference failing to resolve, e.g. an entry deleted from MyAnimeList, does not stop the others
+    /// from being resolved: its error is returned in its place.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::MALTypeItem;
+    ///
+    /// let jikan = Jikan::new();
+    /// let anime = jikan.find_anime(1).await.unwrap();
+    /// // Returns the producers and studios of Cowboy Bebop
+    /// let companies: Vec<_> = jikan.resolve_all(anime.producers.iter().chain(&anime.studios).filter_map(MALTypeItem::reference))
+    ///     .await
+    ///     .into_iter()
+    ///     .filter_map(|(_, entity)| entity.ok())
+    ///     .collect();
+    /// # }
+    /// ```
+    pub async fn resolve_all(&self, references: impl IntoIterator<Item = Reference>) -> Vec<(Reference, Result<Entity>)> {
+        resolve::resolve_all(references, &self.http_client).await
+    }
+
     /// Search results for the query.
     ///
     /// # Examples
@@ -697,4 +1166,288 @@ impl Jikan {
     pub async fn retrieve_request_info(&self, about: InfoAbout, period: Period, offset: u32) -> Result<HashMap<String, u16>> {
         meta::retrieve_request_info(about, period, offset, &self.http_client).await
     }
-}
\ No newline at end of file
+}
+/// Paginated endpoints as lazy streams.
+///
+/// Each endpoint has a stream of items and a stream of whole pages. Pages are fetched one at a
+/// time as the stream is polled, through the rate limiter and cache of the client, until the
+/// last page, an empty page or a missing page is reached.
+impl Jikan {
+    /// Stream the reviews of an anime or manga, starting at `from_page`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use futures::StreamExt;
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::{AnimeId, AnimeReviewer, Review, SourceType};
+    ///
+    /// let jikan = Jikan::new();
+    /// // First 50 reviews of Cowboy Bebop
+    /// let reviews: Vec<_> = jikan.stream_reviews::<AnimeReviewer>(SourceType::Anime(AnimeId(1)), 1)
+    ///     .take(50)
+    ///     .collect()
+    ///     .await;
+    /// # }
+    /// ```
+    pub fn stream_reviews<R>(&self, mal_id: SourceType, from_page: u16) -> BoxStream<'static, Result<Review<R>>>
+        where R: Send + 'static,
+              Vec<Review<R>>: TryFrom<Reviews, Error=Error> {
+        pagination::items(self.stream_reviews_pages(mal_id, from_page))
+    }
+
+    /// Stream the pages of reviews of an anime or manga, starting at `from_page`.
+    pub fn stream_reviews_pages<R>(&self, mal_id: SourceType, from_page: u16) -> BoxStream<'static, Result<Page<Review<R>>>>
+        where R: Send + 'static,
+              Vec<Review<R>>: TryFrom<Reviews, Error=Error> {
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let reviews = reviews::find_reviews(mal_id, page, &http_client).await?;
+                Ok((Vec::try_from(reviews)?, None))
+            }
+        })
+    }
+
+    /// Stream the latest list updates of an anime or manga, starting at `from_page`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use futures::StreamExt;
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::{MangaId, MangaUserUpdate, SourceType};
+    ///
+    /// let jikan = Jikan::new();
+    /// let mut updates = jikan.stream_user_updates::<MangaUserUpdate>(SourceType::Manga(MangaId(1)), 1);
+    /// while let Some(update) = updates.next().await {
+    ///     println!("{}", update.unwrap().username);
+    /// }
+    /// # }
+    /// ```
+    pub fn stream_user_updates<U>(&self, mal_id: SourceType, from_page: u16) -> BoxStream<'static, Result<U>>
+        where U: Send + 'static,
+              Vec<U>: TryFrom<UserUpdates, Error=Error> {
+        pagination::items(self.stream_user_updates_pages(mal_id, from_page))
+    }
+
+    /// Stream the pages of latest list updates of an anime or manga, starting at `from_page`.
+    pub fn stream_user_updates_pages<U>(&self, mal_id: SourceType, from_page: u16) -> BoxStream<'static, Result<Page<U>>>
+        where U: Send + 'static,
+              Vec<U>: TryFrom<UserUpdates, Error=Error> {
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let user_updates = user_updates::find_user_updates(mal_id, page, &http_client).await?;
+                Ok((Vec::try_from(user_updates)?, None))
+            }
+        })
+    }
+
+    /// Stream the episodes of an anime, starting at `from_page`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use futures::StreamExt;
+    /// use jikan_rs::client::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// let mut episodes = jikan.stream_episodes(21, 1);
+    /// while let Some(episode) = episodes.next().await {
+    ///     println!("{:?}", episode.unwrap().title);
+    /// }
+    /// # }
+    /// ```
+    pub fn stream_episodes(&self, mal_id: impl Into<AnimeId>, from_page: u16) -> BoxStream<'static, Result<EpisodeInfo>> {
+        pagination::items(self.stream_episodes_pages(mal_id, from_page))
+    }
+
+    /// Stream the pages of episodes of an anime, starting at `from_page`.
+    pub fn stream_episodes_pages(&self, mal_id: impl Into<AnimeId>, from_page: u16) -> BoxStream<'static, Result<Page<EpisodeInfo>>> {
+        let mal_id: AnimeId = mal_id.into();
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let page = anime::episodes::find_episodes_page(mal_id, page, &http_client).await?;
+                Ok((page.items, page.last_page))
+            }
+        })
+    }
+
+    /// Stream the animes made by a producer, starting at `from_page`.
+    pub fn stream_producer(&self, id: impl Into<ProducerId>, from_page: u16) -> BoxStream<'static, Result<AnimeInfo>> {
+        pagination::items(self.stream_producer_pages(id, from_page))
+    }
+
+    /// Stream the pages of animes made by a producer, starting at `from_page`.
+    pub fn stream_producer_pages(&self, id: impl Into<ProducerId>, from_page: u16) -> BoxStream<'static, Result<Page<AnimeInfo>>> {
+        let id: ProducerId = id.into();
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let producer = producer::find_producer(id, page, &http_client).await?;
+                Ok((producer.animes, None))
+            }
+        })
+    }
+
+    /// Stream the mangas serialized in a magazine, starting at `from_page`.
+    pub fn stream_magazine(&self, id: impl Into<MagazineId>, from_page: u16) -> BoxStream<'static, Result<MangaInfo>> {
+        pagination::items(self.stream_magazine_pages(id, from_page))
+    }
+
+    /// Stream the pages of mangas serialized in a magazine, starting at `from_page`.
+    pub fn stream_magazine_pages(&self, id: impl Into<MagazineId>, from_page: u16) -> BoxStream<'static, Result<Page<MangaInfo>>> {
+        let id: MagazineId = id.into();
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let magazine = magazine::find_magazine(id, page, &http_client).await?;
+                Ok((magazine.mangas, None))
+            }
+        })
+    }
+
+    /// Stream the members of a club, starting at `from_page`.
+    pub fn stream_club_members(&self, club_id: impl Into<ClubId>, from_page: u16) -> BoxStream<'static, Result<ClubMember>> {
+        pagination::items(self.stream_club_members_pages(club_id, from_page))
+    }
+
+    /// Stream the pages of members of a club, starting at `from_page`.
+    pub fn stream_club_members_pages(&self, club_id: impl Into<ClubId>, from_page: u16) -> BoxStream<'static, Result<Page<ClubMember>>> {
+        let club_id: ClubId = club_id.into();
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let members = club::find_club_members(club_id, page, &http_client).await?;
+                Ok((members, None))
+            }
+        })
+    }
+
+    /// Stream the animes of a genre, starting at `from_page`.
+    pub fn stream_animes_with_genre(&self, genre: AnimeGenre, from_page: u16) -> BoxStream<'static, Result<AnimeInfo>> {
+        pagination::items(self.stream_animes_with_genre_pages(genre, from_page))
+    }
+
+    /// Stream the pages of animes of a genre, starting at `from_page`.
+    pub fn stream_animes_with_genre_pages(&self, genre: AnimeGenre, from_page: u16) -> BoxStream<'static, Result<Page<AnimeInfo>>> {
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let result = genre::find_animes_with_genre(genre, page, &http_client).await?;
+                Ok((result.animes, None))
+            }
+        })
+    }
+
+    /// Stream the mangas of a genre, starting at `from_page`.
+    pub fn stream_mangas_with_genre(&self, genre: MangaGenre, from_page: u16) -> BoxStream<'static, Result<MangaInfo>> {
+        pagination::items(self.stream_mangas_with_genre_pages(genre, from_page))
+    }
+
+    /// Stream the pages of mangas of a genre, starting at `from_page`.
+    pub fn stream_mangas_with_genre_pages(&self, genre: MangaGenre, from_page: u16) -> BoxStream<'static, Result<Page<MangaInfo>>> {
+        let http_client = self.http_client.clone();
+        pagination::pages(from_page, move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let result = genre::find_mangas_with_genre(genre, page, &http_client).await?;
+                Ok((result.mangas, None))
+            }
+        })
+    }
+
+    /// Stream a top list, starting at the page given in `top`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use futures::StreamExt;
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::{RankedAnime, Top, TopAnimeSubtype};
+    ///
+    /// let jikan = Jikan::new();
+    /// // Top 200 airing animes, 50 per page
+    /// let top_airing: Vec<_> = jikan.stream_top::<RankedAnime>(Top::Anime { page: 1, subtype: TopAnimeSubtype::Airing })
+    ///     .take(200)
+    ///     .collect()
+    ///     .await;
+    /// # }
+    /// ```
+    pub fn stream_top<T>(&self, top: Top) -> BoxStream<'static, Result<T>>
+        where T: Send + 'static,
+              Vec<T>: TryFrom<TopResult, Error=Error> {
+        pagination::items(self.stream_top_pages(top))
+    }
+
+    /// Stream the pages of a top list, starting at the page given in `top`.
+    pub fn stream_top_pages<T>(&self, top: Top) -> BoxStream<'static, Result<Page<T>>>
+        where T: Send + 'static,
+              Vec<T>: TryFrom<TopResult, Error=Error> {
+        let http_client = self.http_client.clone();
+        pagination::pages(top.page(), move |page| {
+            let http_client = http_client.clone();
+            async move {
+                let top_result = top::find_top(top.with_page(page), &http_client).await?;
+                Ok((Vec::try_from(top_result)?, None))
+            }
+        })
+    }
+
+    /// Stream search results, starting at the page set in the query.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use futures::StreamExt;
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::{AnimeResult, SearchQueryBuilder, SearchSource};
+    ///
+    /// let jikan = Jikan::new();
+    /// // Resume a search from its third page
+    /// let query = SearchQueryBuilder::new(SearchSource::Anime).name("gundam").page(3);
+    /// let gundams: Vec<_> = jikan.stream_search::<AnimeResult>(query)
+    ///     .collect()
+    ///     .await;
+    /// # }
+    /// ```
+    pub fn stream_search<T>(&self, query_builder: SearchQueryBuilder) -> BoxStream<'static, Result<T>>
+        where T: Send + 'static,
+              SearchResult<T>: TryFrom<SearchResultEnum, Error=Error> {
+        pagination::items(self.stream_search_pages(query_builder))
+    }
+
+    /// Stream the pages of search results, starting at the page set in the query.
+    pub fn stream_search_pages<T>(&self, query_builder: SearchQueryBuilder) -> BoxStream<'static, Result<Page<T>>>
+        where T: Send + 'static,
+              SearchResult<T>: TryFrom<SearchResultEnum, Error=Error> {
+        let query = match query_builder.build() {
+            Ok(query) => Arc::new(query),
+            Err(err) => return stream::once(async { Err(err) }).boxed(),
+        };
+        let http_client = self.http_client.clone();
+        pagination::pages(query.page(), move |page| {
+            let http_client = http_client.clone();
+            let query = query.clone();
+            async move {
+                let search_result = search::search_page(&query, page, &http_client).await?;
+                let search_result = SearchResult::try_from(search_result)?;
+                Ok((search_result.results, Some(search_result.last_page)))
+            }
+        })
+    }
+}
@@ -1,42 +1,36 @@
-use reqwest::Client;
-
 use crate::base::MALTypeItem;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::datetime::DateTime;
+use crate::error::{from_body, from_json, Result};
+use crate::id::ClubId;
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_club(mal_id: u32, http_clt: &Client) -> Result<Club> {
-    let url = format!("{}/club/{}", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let club: Club = serde_json::from_str(&body)?;
+pub(crate) async fn find_club(mal_id: ClubId, http_clt: &HttpClient) -> Result<Club> {
+    let url = format!("{}/club/{}", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let club: Club = from_body(&body)?;
 
     Ok(club)
 }
 
-pub(crate) async fn find_club_members(club_id: u32, page: u32, http_clt: &Client) -> Result<Vec<ClubMember>> {
-    let url = format!("{}/club/{}/members/{}", BASE_URL, club_id, page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let response: ClubMembersResponse = serde_json::from_str(&body)?;
+pub(crate) async fn find_club_members(club_id: ClubId, page: u16, http_clt: &HttpClient) -> Result<Vec<ClubMember>> {
+    let url = format!("{}/club/{}/members/{}", http_clt.base_url(), club_id, page);
+    let body = request::get(&url, http_clt).await?;
+    let response: ClubMembersResponse = from_json(&body)?;
 
     Ok(response.members)
 }
 
 jikan_response_entity!(
+    #[derive(Eq, Hash)]
     pub struct Club {
-        pub mal_id: u32,
+        pub mal_id: ClubId,
         pub url: String,
         pub image_url: Option<String>,
         pub title: String,
         pub members_count: u32,
         pub pictures_count: u32,
         pub category: Option<String>,
-        pub created: Option<String>,
+        pub created: Option<DateTime>,
         #[serde(rename = "type")]
         pub club_type: Option<String>,
         pub staff: Vec<MALTypeItem>,
@@ -52,7 +46,7 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ClubMember {
     pub username: String,
     pub url: String,
@@ -0,0 +1,178 @@
+//! Dates sent by Jikan.
+//!
+//! Dates are kept as sent whatever the features, so enabling `chrono` does not change the type of
+//! any field. They are read once when deserialized: `DateTime::date` returns them with the precision
+//! Jikan gave, "Apr 1998" is April 1998, not its first day, and `DateTime` values are ordered by that
+//! date. With the `chrono` feature, `DateTime::to_chrono` and `DateTime::to_naive_date` return the
+//! dates precise enough to be represented by chrono, or say why they are not.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "chrono")]
+use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+use crate::base::Date;
+#[cfg(feature = "chrono")]
+use crate::error::{Error, Result};
+
+const MONTHS: [&str; 12] = ["Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec"];
+
+#[cfg(feature = "chrono")]
+const UNKNOWN_FORMAT: &str = "unknown format";
+#[cfg(feature = "chrono")]
+const NO_DAY: &str = "no day given";
+
+/// Date, or date and time, as sent by Jikan.
+///
+/// Two values are equal when Jikan sent the same text. They are ordered by their date, the dates
+/// Jikan left unknown first, then by their text, so "Apr 1998" comes before "Apr 3, 1998".
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(from = "String", into = "String")]
+pub struct DateTime {
+    raw: String,
+    date: Option<Date>,
+    #[cfg(feature = "chrono")]
+    chrono: Option<chrono::DateTime<FixedOffset>>,
+}
+
+impl DateTime {
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// Returns the date, keeping the month and day unknown when Jikan left them out.
+    ///
+    /// Known for RFC 3339 timestamps and the "Apr 3, 1998", "Apr 1998" and "1998" forms, other
+    /// values such as "?" return `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jikan_rs::prelude::{Date, DateTime};
+    ///
+    /// let date = DateTime::from("Apr 1998".to_string()).date().unwrap();
+    /// assert_eq!(date, Date::from_year_month(1998, 4).unwrap());
+    /// assert_eq!(date.day(), None);
+    /// ```
+    pub fn date(&self) -> Option<Date> {
+        self.date
+    }
+
+    /// Returns the date and time, at midnight UTC for the "Apr 3, 1998" form.
+    ///
+    /// Fails with `Error::UnconvertibleDate` for unknown dates and dates without a day.
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> Result<chrono::DateTime<FixedOffset>> {
+        self.chrono.ok_or_else(|| self.unconvertible())
+    }
+
+    /// Returns the day.
+    ///
+    /// Fails with `Error::UnconvertibleDate` for unknown dates and dates without a day.
+    #[cfg(feature = "chrono")]
+    pub fn to_naive_date(&self) -> Result<NaiveDate> {
+        self.chrono.map(|datetime| datetime.date_naive()).ok_or_else(|| self.unconvertible())
+    }
+
+    #[cfg(feature = "chrono")]
+    fn unconvertible(&self) -> Error {
+        let reason = if self.date.is_some() { NO_DAY } else { UNKNOWN_FORMAT };
+        Error::UnconvertibleDate {
+            date: self.raw.clone(),
+            reason,
+        }
+    }
+}
+
+fn parse_date(value: &str) -> Option<Date> {
+    let value = value.trim();
+    if let Some((date, _)) = value.split_once('T') {
+        return date.parse().ok();
+    }
+    let (month_day, year) = match value.split_once(", ") {
+        Some((month_day, year)) => (Some(month_day), year),
+        None => match value.split_once(' ') {
+            Some((month, year)) => (Some(month), year),
+            None => (None, value),
+        },
+    };
+    if year.len() != 4 || !year.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let year = year.parse().ok()?;
+    let (month, day) = match month_day {
+        Some(month_day) => match month_day.split_once(' ') {
+            Some((month, day)) => (month, Some(day.parse().ok()?)),
+            None => (month_day, None),
+        },
+        None => return Date::from_year(year).ok(),
+    };
+    let month = MONTHS.iter().position(|name| *name == month)? as u8 + 1;
+    match day {
+        Some(day) => Date::new(year, month, day).ok(),
+        None => Date::from_year_month(year, month).ok(),
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn parse_chrono(value: &str, date: Option<Date>) -> Option<chrono::DateTime<FixedOffset>> {
+    if let Ok(datetime) = chrono::DateTime::parse_from_rfc3339(value.trim()) {
+        return Some(datetime);
+    }
+    let date = date?;
+    let day = NaiveDate::from_ymd_opt(i32::from(date.year()), u32::from(date.month()?), u32::from(date.day()?))?;
+    let utc = FixedOffset::east_opt(0)?;
+    Some(utc.from_utc_datetime(&day.and_hms_opt(0, 0, 0)?))
+}
+
+impl From<String> for DateTime {
+    fn from(raw: String) -> Self {
+        let date = parse_date(&raw);
+        DateTime {
+            #[cfg(feature = "chrono")]
+            chrono: parse_chrono(&raw, date),
+            date,
+            raw,
+        }
+    }
+}
+
+impl From<DateTime> for String {
+    fn from(value: DateTime) -> Self {
+        value.raw
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for DateTime {}
+
+impl Hash for DateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.date.cmp(&other.date).then_with(|| self.raw.cmp(&other.raw))
+    }
+}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
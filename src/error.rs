@@ -0,0 +1,182 @@
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+
+use crate::response::{Body, WithHttpMeta};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors returned by every call made through the Jikan client.
+#[derive(Debug)]
+pub enum Error {
+    /// The requested resource does not exist on MyAnimeList.
+    NotFound {
+        error: Option<ApiError>,
+    },
+    /// Jikan rejected the parameters of the request.
+    BadRequest {
+        error: Option<ApiError>,
+    },
+    /// Jikan refused the request because the rate limit was exceeded.
+    RateLimited {
+        error: Option<ApiError>,
+    },
+    /// Jikan or MyAnimeList failed to serve the request.
+    Upstream {
+        status: u16,
+        error: Option<ApiError>,
+    },
+    /// Jikan answered with any other unsuccessful HTTP status.
+    Http {
+        status: u16,
+        error: Option<ApiError>,
+    },
+    /// The response body does not match the expected model.
+    Deserialize {
+        path: String,
+        body: String,
+        source: serde_json::Error,
+    },
+    /// The request could not be sent or its response could not be read.
+    Transport(reqwest::Error),
+    /// A custom transport failed to send the request.
+    CustomTransport(Box<dyn std::error::Error + Send + Sync>),
+    /// Jikan returned a different kind of result than the one requested,
+    /// e.g. Manga Stats when Anime Stats were expected.
+    UnexpectedResult {
+        expected: &'static str,
+        returned: &'static str,
+    },
+    /// The requested resource is not available for the given source type.
+    UnsupportedSource {
+        resource: &'static str,
+    },
+    /// The cache storage could not be read or written.
+    Cache(std::io::Error),
+    /// A query date is out of range or badly formatted.
+    InvalidDate(String),
+    /// A reference does not point to an entity that can be fetched, e.g. a genre or a user.
+    Unresolvable(String),
+    /// A date sent by Jikan cannot be converted, as its format is unknown or it lacks a day.
+    UnconvertibleDate {
+        date: String,
+        reason: &'static str,
+    },
+}
+
+/// Error body sent by Jikan along with unsuccessful responses.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct ApiError {
+    pub status: u16,
+    #[serde(rename = "type")]
+    pub error_type: String,
+    pub message: String,
+    pub error: Option<String>,
+}
+
+impl Error {
+    pub(crate) fn from_status(status: u16, body: &str) -> Error {
+        let error = serde_json::from_str::<ApiError>(body).ok();
+        match status {
+            400 => Error::BadRequest { error },
+            404 => Error::NotFound { error },
+            429 => Error::RateLimited { error },
+            500..=599 => Error::Upstream { status, error },
+            _ => Error::Http { status, error },
+        }
+    }
+
+    /// Returns the HTTP status that caused the error, if any.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::NotFound { .. } => Some(404),
+            Error::BadRequest { .. } => Some(400),
+            Error::RateLimited { .. } => Some(429),
+            Error::Upstream { status, .. } | Error::Http { status, .. } => Some(*status),
+            Error::Transport(err) => err.status().map(|status| status.as_u16()),
+            _ => None,
+        }
+    }
+
+    /// Returns the error body sent by Jikan, if any.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Error::NotFound { error }
+            | Error::BadRequest { error }
+            | Error::RateLimited { error }
+            | Error::Upstream { error, .. }
+            | Error::Http { error, .. } => error.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Error::NotFound { .. })
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound { .. } => write!(f, "Resource does not exist")?,
+            Error::BadRequest { .. } => write!(f, "Invalid request")?,
+            Error::RateLimited { .. } => write!(f, "Rate limited by Jikan")?,
+            Error::Upstream { status, .. } => write!(f, "Jikan failed to serve the request with status {}", status)?,
+            Error::Http { status, .. } => write!(f, "Jikan returned status {}", status)?,
+            Error::Deserialize { path, source, .. } => write!(f, "Unable to deserialize response at '{}': {}", path, source)?,
+            Error::Transport(err) => write!(f, "Unable to reach Jikan: {}", err)?,
+            Error::CustomTransport(err) => write!(f, "Unable to reach Jikan: {}", err)?,
+            Error::UnexpectedResult { expected, returned } => write!(f, "Expected {}, but returned {}", expected, returned)?,
+            Error::UnsupportedSource { resource } => write!(f, "There is no {} for this type source", resource)?,
+            Error::Cache(err) => write!(f, "Unable to access the cache: {}", err)?,
+            Error::InvalidDate(date) => write!(f, "Invalid date '{}', expected YYYY, YYYY-MM or YYYY-MM-DD", date)?,
+            Error::Unresolvable(url) => write!(f, "Unable to resolve the entity referenced by '{}'", url)?,
+            Error::UnconvertibleDate { date, reason } => write!(f, "Unable to convert the date '{}': {}", date, reason)?,
+        }
+        match self.api_error() {
+            Some(error) => write!(f, ": {}", error.message),
+            None => Ok(()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Deserialize { source, .. } => Some(source),
+            Error::Transport(err) => Some(err),
+            Error::CustomTransport(err) => Some(err.as_ref()),
+            Error::Cache(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Transport(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Cache(err)
+    }
+}
+
+pub(crate) fn from_json<T: DeserializeOwned>(body: &str) -> Result<T> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|err| Error::Deserialize {
+            path: err.path().to_string(),
+            body: body.to_string(),
+            source: err.into_inner(),
+        })
+}
+
+/// Deserializes an entity and attaches the HTTP metadata of the response to it.
+pub(crate) fn from_body<T: DeserializeOwned + WithHttpMeta>(body: &Body) -> Result<T> {
+    let mut entity: T = from_json(body)?;
+    entity.set_http_meta(body.http_meta);
+    Ok(entity)
+}
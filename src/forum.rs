@@ -1,18 +1,13 @@
-use reqwest::Client;
-
 use crate::base::Resource;
 use crate::base::SourceType;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::datetime::DateTime;
+use crate::error::{from_json, Result};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_forum(mal_id: SourceType, http_clt: &Client) -> Result<Vec<Topic>> {
-    let url = format!("{}{}/forum", BASE_URL, mal_id.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let response: Response = serde_json::from_str(&body)?;
+pub(crate) async fn find_forum(mal_id: SourceType, http_clt: &HttpClient) -> Result<Vec<Topic>> {
+    let url = format!("{}{}/forum", http_clt.base_url(), mal_id.uri());
+    let body = request::get(&url, http_clt).await?;
+    let response: Response = from_json(&body)?;
 
     Ok(response.topics)
 }
@@ -23,21 +18,21 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Topic {
     pub topic_id: u32,
     pub url: Option<String>,
     pub title: Option<String>,
-    pub date_posted: Option<String>,
+    pub date_posted: Option<DateTime>,
     pub author_name: Option<String>,
     pub replies: u32,
     pub last_post: Option<LastPost>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct LastPost {
     pub url: Option<String>,
     pub author_name: Option<String>,
     pub author_url: Option<String>,
-    pub date_posted: Option<String>,
+    pub date_posted: Option<DateTime>,
 }
\ No newline at end of file
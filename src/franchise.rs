@@ -0,0 +1,209 @@
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::fmt::Write;
+
+use crate::{anime, manga};
+use crate::anime::Anime;
+use crate::base::{EntityRef, RelatedContent, RelationKind};
+use crate::error::Result;
+use crate::manga::Manga;
+use crate::request::HttpClient;
+
+const DEFAULT_MAX_ENTRIES: usize = 100;
+
+builder!(
+    pub struct FranchiseQuery {
+        pub(crate) max_entries: usize,
+        pub(crate) relations: Vec<RelationKind>,
+    }
+);
+
+// Character and Other relations link entries sharing a cameo or a theme, they would pull unrelated franchises in
+fn default_relations() -> Vec<RelationKind> {
+    vec![
+        RelationKind::Prequel,
+        RelationKind::Sequel,
+        RelationKind::SideStory,
+        RelationKind::ParentStory,
+        RelationKind::SpinOff,
+        RelationKind::Adaptation,
+        RelationKind::AlternativeVersion,
+        RelationKind::AlternativeSetting,
+        RelationKind::Summary,
+        RelationKind::FullStory,
+    ]
+}
+
+pub(crate) async fn find_franchise(seed: EntityRef, seed_entry: Option<WalkEntry>, query: FranchiseQuery,
+                                   http_clt: &HttpClient) -> Result<Franchise> {
+    let max_entries = query.max_entries.unwrap_or(DEFAULT_MAX_ENTRIES);
+    let relations = query.relations.unwrap_or_else(default_relations);
+    let walk = walk(seed, seed_entry, max_entries, |kind, _| relations.contains(kind), http_clt).await?;
+
+    let mut entries: Vec<FranchiseEntry> = walk.entries.iter()
+        .map(|(entity, entry)| FranchiseEntry { entity: *entity, title: entry.title().to_string() })
+        .collect();
+    entries.sort_by_key(|entry| entry.entity);
+    Ok(Franchise { seed, entries, edges: walk.edges, truncated: walk.truncated })
+}
+
+/// Anime or manga fetched while walking a franchise.
+pub(crate) enum WalkEntry {
+    Anime(Box<Anime>),
+    Manga(Box<Manga>),
+}
+
+impl WalkEntry {
+    fn title(&self) -> &str {
+        match self {
+            WalkEntry::Anime(anime) => &anime.title,
+            WalkEntry::Manga(manga) => &manga.title,
+        }
+    }
+
+    fn related(&self) -> &RelatedContent {
+        match self {
+            WalkEntry::Anime(anime) => &anime.related,
+            WalkEntry::Manga(manga) => &manga.related,
+        }
+    }
+}
+
+pub(crate) struct Walk {
+    /// Entries in the order they were fetched, the seed first.
+    pub(crate) entries: Vec<(EntityRef, WalkEntry)>,
+    /// Followed relations between fetched entries, sorted.
+    pub(crate) edges: Vec<FranchiseEdge>,
+    pub(crate) truncated: bool,
+}
+
+/// Walks breadth first the relations accepted by `follow`, fetching each entry once.
+///
+/// The seed is only fetched when `seed_entry` does not hold it already.
+pub(crate) async fn walk<F>(seed: EntityRef, mut seed_entry: Option<WalkEntry>, max_entries: usize, follow: F,
+                            http_clt: &HttpClient) -> Result<Walk>
+    where F: Fn(&RelationKind, EntityRef) -> bool
+{
+    // Jikan answers bursts of requests with 429s, so the walk is throttled even if the client is not
+    let http_clt = &http_clt.for_walk();
+    let max_entries = max_entries.max(1);
+    let mut queued = HashSet::new();
+    let mut queue = VecDeque::new();
+    let mut entries = Vec::new();
+    let mut edges = BTreeSet::new();
+    let mut truncated = false;
+    queued.insert(seed);
+    queue.push_back(seed);
+
+    // Entries are fetched one at a time, so the walk never exceeds the rate limiter of the client
+    while let Some(entity) = queue.pop_front() {
+        let fetched = match seed_entry.take() {
+            Some(entry) => Ok(entry),
+            None => find_entry(entity, http_clt).await,
+        };
+        let entry = match fetched {
+            Ok(entry) => entry,
+            // MyAnimeList keeps relations to deleted entries around
+            Err(err) if err.is_not_found() && entity != seed => continue,
+            Err(err) => return Err(err),
+        };
+
+        for (kind, related_entity) in entry.related().entities() {
+            if !follow(kind, related_entity) {
+                continue;
+            }
+            edges.insert(FranchiseEdge { from: entity, to: related_entity, relation: kind.clone() });
+            if queued.contains(&related_entity) {
+                continue;
+            }
+            if queued.len() < max_entries {
+                queued.insert(related_entity);
+                queue.push_back(related_entity);
+            } else {
+                truncated = true;
+            }
+        }
+        entries.push((entity, entry));
+    }
+
+    let fetched: HashSet<EntityRef> = entries.iter().map(|(entity, _)| *entity).collect();
+    Ok(Walk {
+        entries,
+        edges: edges.into_iter()
+            .filter(|edge| fetched.contains(&edge.to))
+            .collect(),
+        truncated,
+    })
+}
+
+async fn find_entry(entity: EntityRef, http_clt: &HttpClient) -> Result<WalkEntry> {
+    match entity {
+        EntityRef::Anime(mal_id) => Ok(WalkEntry::Anime(Box::new(anime::find_anime(mal_id, http_clt).await?))),
+        EntityRef::Manga(mal_id) => Ok(WalkEntry::Manga(Box::new(manga::find_manga(mal_id, http_clt).await?))),
+    }
+}
+
+/// Anime and manga reachable from a seed entry through their relations.
+///
+/// Entries are sorted by kind and id and edges by source, target and relation, so two walks of
+/// the same franchise export the same JSON and DOT documents.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Franchise {
+    pub seed: EntityRef,
+    pub entries: Vec<FranchiseEntry>,
+    pub edges: Vec<FranchiseEdge>,
+    /// Whether entries were left out because the walk reached `max_entries`.
+    pub truncated: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FranchiseEntry {
+    pub entity: EntityRef,
+    pub title: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FranchiseEdge {
+    pub from: EntityRef,
+    pub to: EntityRef,
+    pub relation: RelationKind,
+}
+
+impl Franchise {
+    pub fn entry(&self, entity: EntityRef) -> Option<&FranchiseEntry> {
+        self.entries.iter().find(|entry| entry.entity == entity)
+    }
+
+    /// Iterates over the edges leaving the entry.
+    pub fn relations_of(&self, entity: EntityRef) -> impl Iterator<Item = &FranchiseEdge> {
+        self.edges.iter().filter(move |edge| edge.from == entity)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("franchise graphs only hold serializable values")
+    }
+
+    /// Exports the graph in the Graphviz DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph franchise {\n");
+        for entry in &self.entries {
+            let _ = writeln!(dot, "    \"{}\" [label=\"{}\"];", node_id(entry.entity), escape(&entry.title));
+        }
+        for edge in &self.edges {
+            let relation = String::from(edge.relation.clone());
+            let _ = writeln!(dot, "    \"{}\" -> \"{}\" [label=\"{}\"];", node_id(edge.from), node_id(edge.to), escape(&relation));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn node_id(entity: EntityRef) -> String {
+    match entity {
+        EntityRef::Anime(mal_id) => format!("anime/{}", mal_id),
+        EntityRef::Manga(mal_id) => format!("manga/{}", mal_id),
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
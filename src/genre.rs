@@ -1,29 +1,20 @@
-use reqwest::Client;
-
 use crate::base::{AnimeInfo, MALTypeItem, MangaInfo};
-use crate::client::BASE_URL;
+use crate::error::{from_body, Result};
+use crate::request::{self, HttpClient};
 use crate::search::enums::{AnimeGenre, MangaGenre};
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_animes_with_genre(genre: AnimeGenre, page: u16, http_clt: &Client) -> Result<GenreAnimeResult> {
-    let url = format!("{}/genre/anime/{}/{}", BASE_URL, genre as u8, page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let animes: GenreAnimeResult = serde_json::from_str(&body)?;
+pub(crate) async fn find_animes_with_genre(genre: AnimeGenre, page: u16, http_clt: &HttpClient) -> Result<GenreAnimeResult> {
+    let url = format!("{}/genre/anime/{}/{}", http_clt.base_url(), genre as u8, page);
+    let body = request::get(&url, http_clt).await?;
+    let animes: GenreAnimeResult = from_body(&body)?;
 
     Ok(animes)
 }
 
-pub(crate) async fn find_mangas_with_genre(genre: MangaGenre, page: u16, http_clt: &Client) -> Result<GenreMangaResult> {
-    let url = format!("{}/genre/manga/{}/{}", BASE_URL, genre as u8, page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let mangas: GenreMangaResult = serde_json::from_str(&body)?;
+pub(crate) async fn find_mangas_with_genre(genre: MangaGenre, page: u16, http_clt: &HttpClient) -> Result<GenreMangaResult> {
+    let url = format!("{}/genre/manga/{}/{}", http_clt.base_url(), genre as u8, page);
+    let body = request::get(&url, http_clt).await?;
+    let mangas: GenreMangaResult = from_body(&body)?;
 
     Ok(mangas)
 }
@@ -0,0 +1,50 @@
+//! MyAnimeList ids of each kind of entity.
+//!
+//! Ids are distinct types so an anime id cannot be passed where a manga id is expected. Plain
+//! integers convert into any of them, and ids compare to integers.
+
+use std::fmt;
+
+macro_rules! mal_id {
+    ($( $name:ident ),* $(,)?) => {
+        $(
+            #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+            #[serde(transparent)]
+            pub struct $name(pub u32);
+
+            impl From<u32> for $name {
+                fn from(mal_id: u32) -> Self {
+                    $name(mal_id)
+                }
+            }
+
+            impl From<$name> for u32 {
+                fn from(id: $name) -> Self {
+                    id.0
+                }
+            }
+
+            impl PartialEq<u32> for $name {
+                fn eq(&self, other: &u32) -> bool {
+                    self.0 == *other
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+        )*
+    };
+}
+
+mal_id!(
+    AnimeId,
+    MangaId,
+    PersonId,
+    CharacterId,
+    ClubId,
+    ProducerId,
+    MagazineId,
+);
@@ -5,7 +5,10 @@ extern crate serde_derive;
 
 #[macro_use]
 mod macros;
+pub mod cache;
 pub mod client;
+pub mod transport;
+pub mod v4;
 mod anime;
 mod manga;
 mod person;
@@ -15,43 +18,72 @@ mod user;
 mod news;
 mod pictures;
 mod forum;
+mod franchise;
 mod more_info;
 mod recommendations;
 mod reviews;
 mod user_updates;
 mod stats;
 mod base;
+mod datetime;
 mod season;
 mod schedule;
 mod top;
 mod genre;
+mod id;
 mod producer;
 mod magazine;
 mod club;
+mod random;
+mod resolve;
 mod meta;
+mod pagination;
+mod error;
+mod request;
+mod response;
+mod rate_limit;
+mod retry;
+mod watch_order;
+
+pub use crate::anime::airing;
+pub use crate::datetime::DateTime;
+pub use crate::error::{ApiError, Error, Result};
+pub use crate::id::{AnimeId, CharacterId, ClubId, MagazineId, MangaId, PersonId, ProducerId};
+pub use crate::rate_limit::RateLimiter;
+pub use crate::response::ResponseMeta;
+pub use crate::retry::RetryPolicy;
 
 pub mod prelude {
     pub use crate::anime::{Aired, Anime};
+    pub use crate::anime::airing::{Broadcast, Weekday};
     pub use crate::anime::characters::{AnimeCharacter, CharactersStaff, StaffMember};
     pub use crate::anime::episodes::EpisodeInfo;
+    pub use crate::anime::resources::{ExternalLink, Themes};
     pub use crate::anime::videos::{EpisodeVideo, PromoVideo, Videos};
-    pub use crate::base::{AnimeInfo, AnimeStatusForUser, Date, MALImageItem, MALRoleItem, MALTypeItem,
-                          MangaInfo, MangaStatusForUser, RelatedContent, SourceType, VoiceActor};
+    pub use crate::base::{AnimeInfo, AnimeStatusForUser, ContentType, Date, EntityRef, MALImageItem, MALRoleItem,
+                          MALTypeItem, MangaInfo, MangaStatusForUser, RelatedContent, Relation, RelationKind,
+                          SourceType, VoiceActor};
+    pub use crate::datetime::DateTime;
     pub use crate::character::Character;
     pub use crate::club::{Club, ClubMember};
     pub use crate::forum::{LastPost, Topic};
+    pub use crate::franchise::{Franchise, FranchiseEdge, FranchiseEntry, FranchiseQuery};
     pub use crate::genre::{GenreAnimeResult, GenreMangaResult};
+    pub use crate::id::{AnimeId, CharacterId, ClubId, MagazineId, MangaId, PersonId, ProducerId};
     pub use crate::magazine::Magazine;
     pub use crate::manga::{Manga, Published};
     pub use crate::meta::{ApiStatus, InfoAbout, Period};
     pub use crate::news::News;
+    pub use crate::pagination::Page;
     pub use crate::person::{AnimeStaffPosition, Person, PublishedManga, VoiceActingRole};
     pub use crate::pictures::Picture;
     pub use crate::producer::Producer;
     pub use crate::recommendations::Recommendation;
+    pub use crate::resolve::{Entity, Reference};
+    pub use crate::response::ResponseMeta;
     pub use crate::reviews::{AnimeReviewer, AnimeScores, MangaReviewer, MangaScores, Review, Reviews};
     pub use crate::schedule::{Schedule, ScheduleOn};
-    pub use crate::search::enums::{AnimeGenre, AnimeStatus, AnimeType, Genres, MangaGenre, MangaStatus,
+    pub use crate::search::enums::{AnimeGenre, AnimeSource, AnimeStatus, AnimeType, Genres, MangaGenre, MangaStatus,
                                    MangaType, OrderBy, Rating, SearchSource, SearchSourceType, Sort, SourceStatus};
     pub use crate::search::results::{AnimeResult, CharacterResult, MangaResult, PersonResult, SearchResult, SearchResultEnum};
     pub use crate::search::SearchQueryBuilder;
@@ -62,4 +94,5 @@ pub mod prelude {
     pub use crate::user::results::{AnimeListEntry, Friend, HistoryItem, MangaListEntry, Profile, UserResultEnum};
     pub use crate::user::UserInfo;
     pub use crate::user_updates::{AnimeUserUpdate, MangaUserUpdate, UserUpdates};
+    pub use crate::watch_order::{WatchOrder, WatchOrderEntry, WatchOrderQuery};
 }
\ No newline at end of file
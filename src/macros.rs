@@ -1,21 +1,46 @@
 #[macro_export]
 macro_rules! jikan_response_entity {
-    ($vis_st:vis struct $src_name:ident $(<$T:ident>)? {
+    ($( #[$st_meta:meta] )* $vis_st:vis struct $src_name:ident $(<$T:ident>)? {
         $(
             $( #[$attr_meta:meta] )*
             $attr_vis:vis $attr_name:ident: $attr_type:ty
         ),* $(,)*
     }) => {
-        #[derive(Deserialize, Debug)]
+        #[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+        $( #[$st_meta] )*
         $vis_st struct $src_name $(<$T>)? {
-            request_hash: String,
-            request_cached: bool,
-            request_cache_expiry: u32,
+            request_hash: $crate::response::NotCompared<String>,
+            request_cached: $crate::response::NotCompared<bool>,
+            request_cache_expiry: $crate::response::NotCompared<u32>,
+            #[serde(skip)]
+            http_meta: $crate::response::NotCompared<$crate::response::HttpMeta>,
             $(
             $( #[$attr_meta] )*
             $attr_vis $attr_name: $attr_type
             ),*
         }
+
+        // Internal response wrappers only hand their content over, so `meta` is unused for them
+        #[allow(dead_code)]
+        impl $(<$T>)? $src_name $(<$T>)? {
+            /// Returns the metadata of the response this entity was built from.
+            pub fn meta(&self) -> $crate::response::ResponseMeta {
+                $crate::response::ResponseMeta {
+                    request_hash: self.request_hash.0.clone(),
+                    request_cached: self.request_cached.0,
+                    request_cache_expiry: self.request_cache_expiry.0,
+                    status: self.http_meta.0.status,
+                    response_time: self.http_meta.0.response_time,
+                    from_local_cache: self.http_meta.0.from_local_cache,
+                }
+            }
+        }
+
+        impl $(<$T>)? $crate::response::WithHttpMeta for $src_name $(<$T>)? {
+            fn set_http_meta(&mut self, http_meta: $crate::response::HttpMeta) {
+                self.http_meta = $crate::response::NotCompared(http_meta);
+            }
+        }
     };
 }
 
@@ -47,4 +72,58 @@ macro_rules! builder {
             )*
         }
     }
-}
\ No newline at end of file
+}
+/// Maps the variants of an enum to the strings Jikan uses for them. Strings missing from the
+/// mapping are kept in the `Unknown` variant, so new values sent by Jikan do not break parsing.
+macro_rules! jikan_enum_strings {
+    ($enum_name:ident {
+        $( $variant:ident => $value:literal $( | $alias:literal )* ),* $(,)?
+    }) => {
+        impl From<String> for $enum_name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $( $value $( | $alias )* => $enum_name::$variant, )*
+                    _ => $enum_name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$enum_name> for String {
+            fn from(value: $enum_name) -> Self {
+                match value {
+                    $( $enum_name::$variant => String::from($value), )*
+                    $enum_name::Unknown(value) => value,
+                }
+            }
+        }
+    };
+}
+
+/// Names the variants of a result enum and converts the enum into the content of each variant,
+/// failing with `Error::UnexpectedResult` when the enum holds another variant.
+macro_rules! jikan_result_enum {
+    ($enum_name:ident {
+        $( $variant:ident($content:ty) => $name:literal ),* $(,)?
+    }) => {
+        impl $enum_name {
+            fn name(&self) -> &'static str {
+                match self {
+                    $( $enum_name::$variant(_) => $name, )*
+                }
+            }
+        }
+
+        $(
+        impl ::std::convert::TryFrom<$enum_name> for $content {
+            type Error = $crate::error::Error;
+
+            fn try_from(result: $enum_name) -> ::std::result::Result<Self, Self::Error> {
+                match result {
+                    $enum_name::$variant(content) => Ok(content),
+                    other => Err($crate::error::Error::UnexpectedResult { expected: $name, returned: other.name() }),
+                }
+            }
+        }
+        )*
+    };
+}
@@ -1,17 +1,12 @@
-use reqwest::Client;
-
 use crate::base::{MALTypeItem, MangaInfo};
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_body, Result};
+use crate::id::MagazineId;
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_magazine(id: u32, page: u16, http_clt: &Client) -> Result<Magazine> {
-    let url = format!("{}/magazine/{}/{}", BASE_URL, id, page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let magazine: Magazine = serde_json::from_str(&body)?;
+pub(crate) async fn find_magazine(id: MagazineId, page: u16, http_clt: &HttpClient) -> Result<Magazine> {
+    let url = format!("{}/magazine/{}/{}", http_clt.base_url(), id, page);
+    let body = request::get(&url, http_clt).await?;
+    let magazine: Magazine = from_body(&body)?;
 
     Ok(magazine)
 }
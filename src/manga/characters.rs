@@ -1,17 +1,12 @@
-use reqwest::Client;
-
 use crate::base::MALRoleItem;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_json, Result};
+use crate::id::MangaId;
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_characters(mal_id: u32, http_clt: &Client) -> Result<Vec<MALRoleItem>> {
-    let url = format!("{}/manga/{}/characters", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let response: Response = serde_json::from_str(&body)?;
+pub(crate) async fn find_characters(mal_id: MangaId, http_clt: &HttpClient) -> Result<Vec<MALRoleItem>> {
+    let url = format!("{}/manga/{}/characters", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let response: Response = from_json(&body)?;
 
     Ok(response.characters)
 }
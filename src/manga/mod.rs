@@ -1,27 +1,25 @@
-use reqwest::Client;
-
-use crate::{forum, more_info, news, pictures, recommendations, reviews, stats, user_updates};
-use crate::base::{MALRoleItem, MALTypeItem, RelatedContent, SourceType};
-use crate::client::BASE_URL;
+use crate::{forum, franchise, more_info, news, pictures, recommendations, request, reviews, stats, user_updates};
+use crate::base::{EntityRef, MALRoleItem, MALTypeItem, RelatedContent, SourceType};
+use crate::datetime::DateTime;
+use crate::error::{Error, from_body, Result};
 use crate::forum::Topic;
+use crate::franchise::{Franchise, FranchiseQuery, WalkEntry};
+use crate::id::MangaId;
 use crate::news::News;
 use crate::pictures::Picture;
 use crate::recommendations::Recommendation;
+use crate::request::HttpClient;
 use crate::reviews::{MangaReviewer, Review, Reviews};
+use crate::search::enums::{MangaStatus, MangaType};
 use crate::stats::{MangaStats, Stats};
 use crate::user_updates::{MangaUserUpdate, UserUpdates};
 
 pub mod characters;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_manga(mal_id: u32, http_clt: &Client) -> Result<Manga> {
-    let url = format!("{}/manga/{}", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let mut manga: Manga = serde_json::from_str(&body)?;
+pub(crate) async fn find_manga(mal_id: MangaId, http_clt: &HttpClient) -> Result<Manga> {
+    let url = format!("{}/manga/{}", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let mut manga: Manga = from_body(&body)?;
 
     manga.client = http_clt.clone();
 
@@ -31,17 +29,17 @@ pub(crate) async fn find_manga(mal_id: u32, http_clt: &Client) -> Result<Manga>
 jikan_response_entity!(
     pub struct Manga {
         #[serde(skip)]
-        client: Client,
-        pub mal_id: u32,
+        client: HttpClient,
+        pub mal_id: MangaId,
         pub url: String,
         pub image_url: Option<String>,
         pub title: String,
         pub title_english: Option<String>,
         pub title_japanese: Option<String>,
         pub title_synonyms: Vec<String>,
-        pub status: String,
+        pub status: MangaStatus,
         #[serde(rename = "type")]
-        pub manga_type: String,
+        pub manga_type: MangaType,
         pub volumes: Option<u32>,
         pub chapters: Option<u32>,
         pub publishing: bool,
@@ -61,13 +59,19 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Published {
-    pub from: Option<String>,
-    pub to: Option<String>,
+    pub from: Option<DateTime>,
+    pub to: Option<DateTime>,
 }
 
 impl Manga {
+    /// Gathers the franchise of the manga, see `Jikan::find_franchise`.
+    pub async fn get_franchise(&self, query: FranchiseQuery) -> Result<Franchise> {
+        let seed_entry = WalkEntry::Manga(Box::new(self.clone()));
+        franchise::find_franchise(EntityRef::Manga(self.mal_id), Some(seed_entry), query, &self.client).await
+    }
+
     pub async fn get_characters(&self) -> Result<Vec<MALRoleItem>> {
         characters::find_characters(self.mal_id, &self.client).await
     }
@@ -84,7 +88,7 @@ impl Manga {
         let stats = stats::find_stats(SourceType::Manga(self.mal_id), &self.client).await?;
         match stats {
             Stats::Manga(stats) => Ok(stats),
-            Stats::Anime(_) => Err(Box::from("Expected Manga Stats, but returned Anime Stats")),
+            Stats::Anime(_) => Err(Error::UnexpectedResult { expected: "Manga Stats", returned: "Anime Stats" }),
         }
     }
 
@@ -100,7 +104,7 @@ impl Manga {
         let reviews = reviews::find_reviews(SourceType::Manga(self.mal_id), page, &self.client).await?;
         match reviews {
             Reviews::Manga(reviews) => Ok(reviews),
-            Reviews::Anime(_) => Err(Box::from("Expected Manga Reviews, but returned Anime Reviews")),
+            Reviews::Anime(_) => Err(Error::UnexpectedResult { expected: "Manga Reviews", returned: "Anime Reviews" }),
         }
     }
 
@@ -112,7 +116,7 @@ impl Manga {
         let user_updates = user_updates::find_user_updates(SourceType::Manga(self.mal_id), page, &self.client).await?;
         match user_updates {
             UserUpdates::Manga(user_updates) => Ok(user_updates),
-            UserUpdates::Anime(_) => Err(Box::from("Expected Manga User Updates, but returned Anime User Updates")),
+            UserUpdates::Anime(_) => Err(Error::UnexpectedResult { expected: "Manga User Updates", returned: "Anime User Updates" }),
         }
     }
 }
\ No newline at end of file
@@ -1,35 +1,26 @@
 use std::collections::HashMap;
 
-use reqwest::Client;
-
 use crate::base::Resource;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_json, Result};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn retrieve_api_status(http_clt: &Client) -> Result<ApiStatus> {
-    let url = format!("{}/meta/status", BASE_URL);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let api_status: ApiStatus = serde_json::from_str(&body)?;
+pub(crate) async fn retrieve_api_status(http_clt: &HttpClient) -> Result<ApiStatus> {
+    let url = format!("{}/meta/status", http_clt.base_url());
+    let body = request::get(&url, http_clt).await?;
+    let api_status: ApiStatus = from_json(&body)?;
 
     Ok(api_status)
 }
 
-pub(crate) async fn retrieve_request_info(about: InfoAbout, period: Period, offset: u32, http_clt: &Client) -> Result<HashMap<String, u16>> {
-    let url = format!("{}/meta/requests/{}/{}/{}", BASE_URL, about.uri(), period.uri(), offset);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let request_info: HashMap<String, u16> = serde_json::from_str(&body)?;
+pub(crate) async fn retrieve_request_info(about: InfoAbout, period: Period, offset: u32, http_clt: &HttpClient) -> Result<HashMap<String, u16>> {
+    let url = format!("{}/meta/requests/{}/{}/{}", http_clt.base_url(), about.uri(), period.uri(), offset);
+    let body = request::get(&url, http_clt).await?;
+    let request_info: HashMap<String, u16> = from_json(&body)?;
 
     Ok(request_info)
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ApiStatus {
     pub cached_requests: u32,
     pub requests_today: u32,
@@ -1,18 +1,12 @@
-use reqwest::Client;
-
 use crate::base::Resource;
 use crate::base::SourceType;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_json, Result};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_more_info(mal_id: SourceType, http_clt: &Client) -> Result<Option<String>> {
-    let url = format!("{}{}/moreinfo", BASE_URL, mal_id.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let response: Response = serde_json::from_str(&body)?;
+pub(crate) async fn find_more_info(mal_id: SourceType, http_clt: &HttpClient) -> Result<Option<String>> {
+    let url = format!("{}{}/moreinfo", http_clt.base_url(), mal_id.uri());
+    let body = request::get(&url, http_clt).await?;
+    let response: Response = from_json(&body)?;
 
     Ok(response.moreinfo)
 }
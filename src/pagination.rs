@@ -0,0 +1,46 @@
+use std::future::Future;
+
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+
+use crate::error::Result;
+
+/// A page of results yielded by the paginated streams of `Jikan`.
+#[derive(Serialize, Debug)]
+pub struct Page<T> {
+    pub number: u16,
+    pub items: Vec<T>,
+    /// Last page announced by Jikan, known for search results and episodes.
+    pub last_page: Option<u16>,
+}
+
+/// Lazily fetches successive pages starting at `from_page`.
+///
+/// The stream ends after `last_page`, on the first empty page, or when a page after the
+/// first one does not exist. Any other error is yielded and ends the stream.
+pub(crate) fn pages<T, F, Fut>(from_page: u16, fetch: F) -> BoxStream<'static, Result<Page<T>>>
+    where T: Send + 'static,
+          F: FnMut(u16) -> Fut + Send + 'static,
+          Fut: Future<Output=Result<(Vec<T>, Option<u16>)>> + Send + 'static {
+    stream::unfold((Some(from_page), fetch), move |(page, mut fetch)| async move {
+        let number = page?;
+        match fetch(number).await {
+            Ok((items, _)) if items.is_empty() => None,
+            Ok((items, last_page)) => {
+                let next_page = match last_page {
+                    Some(last_page) if number >= last_page => None,
+                    _ => number.checked_add(1),
+                };
+                Some((Ok(Page { number, items, last_page }), (next_page, fetch)))
+            }
+            Err(err) if err.is_not_found() && number > from_page => None,
+            Err(err) => Some((Err(err), (None, fetch))),
+        }
+    }).boxed()
+}
+
+/// Flattens a stream of pages into a stream of their items.
+pub(crate) fn items<T: Send + 'static>(pages: BoxStream<'static, Result<Page<T>>>) -> BoxStream<'static, Result<T>> {
+    pages.map_ok(|page| stream::iter(page.items.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
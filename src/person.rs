@@ -1,19 +1,15 @@
-use reqwest::Client;
-
 use crate::base::{MALImageItem, SourceType};
-use crate::client::BASE_URL;
+use crate::datetime::DateTime;
+use crate::error::{from_body, Result};
+use crate::id::PersonId;
 use crate::pictures;
 use crate::pictures::Picture;
+use crate::request::{self, HttpClient};
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_person(mal_id: u32, http_clt: &Client) -> Result<Person> {
-    let url = format!("{}/person/{}", BASE_URL, mal_id);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let mut person: Person = serde_json::from_str(&body)?;
+pub(crate) async fn find_person(mal_id: PersonId, http_clt: &HttpClient) -> Result<Person> {
+    let url = format!("{}/person/{}", http_clt.base_url(), mal_id);
+    let body = request::get(&url, http_clt).await?;
+    let mut person: Person = from_body(&body)?;
 
     person.client = http_clt.clone();
 
@@ -21,10 +17,11 @@ pub(crate) async fn find_person(mal_id: u32, http_clt: &Client) -> Result<Person
 }
 
 jikan_response_entity!(
+    #[derive(Eq, Hash)]
     pub struct Person {
         #[serde(skip)]
-        client: Client,
-        pub mal_id: u32,
+        client: HttpClient,
+        pub mal_id: PersonId,
         pub url: String,
         pub image_url: Option<String>,
         pub website_url: Option<String>,
@@ -32,7 +29,7 @@ jikan_response_entity!(
         pub given_name: Option<String>,
         pub family_name: Option<String>,
         pub alternate_names: Vec<String>,
-        pub birthday: Option<String>,
+        pub birthday: Option<DateTime>,
         pub member_favorites: Option<u32>,
         pub about: Option<String>,
         pub voice_acting_roles: Vec<VoiceActingRole>,
@@ -41,20 +38,20 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct VoiceActingRole {
     pub role: String,
     pub anime: MALImageItem,
     pub character: MALImageItem,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnimeStaffPosition {
     pub position: String,
     pub anime: MALImageItem,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PublishedManga {
     pub position: String,
     pub manga: MALImageItem,
@@ -1,17 +1,12 @@
-use reqwest::Client;
-
 use crate::base::{AnimeInfo, MALTypeItem};
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_body, Result};
+use crate::id::ProducerId;
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_producer(id: u32, page: u16, http_clt: &Client) -> Result<Producer> {
-    let url = format!("{}/producer/{}/{}", BASE_URL, id, page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let producer: Producer = serde_json::from_str(&body)?;
+pub(crate) async fn find_producer(id: ProducerId, page: u16, http_clt: &HttpClient) -> Result<Producer> {
+    let url = format!("{}/producer/{}/{}", http_clt.base_url(), id, page);
+    let body = request::get(&url, http_clt).await?;
+    let producer: Producer = from_body(&body)?;
 
     Ok(producer)
 }
@@ -0,0 +1,31 @@
+use crate::error::Result;
+use crate::request::HttpClient;
+use crate::v4;
+
+// Jikan v3 has no random endpoint, v4 picks the entry which is then fetched from v3
+
+#[derive(Deserialize, Debug)]
+struct RandomEntry {
+    mal_id: u32,
+}
+
+#[derive(Deserialize, Debug)]
+struct RandomUser {
+    username: String,
+}
+
+pub(crate) async fn find_random_mal_id(source: &str, http_clt: &HttpClient) -> Result<u32> {
+    let http_clt = http_clt.v4();
+    let url = format!("{}/random/{}", http_clt.base_url(), source);
+    let entry: RandomEntry = v4::get(&url, &http_clt).await?;
+
+    Ok(entry.mal_id)
+}
+
+pub(crate) async fn find_random_username(http_clt: &HttpClient) -> Result<String> {
+    let http_clt = http_clt.v4();
+    let url = format!("{}/random/users", http_clt.base_url());
+    let user: RandomUser = v4::get(&url, &http_clt).await?;
+
+    Ok(user.username)
+}
@@ -0,0 +1,102 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tokio::time::delay_for;
+
+/// Token bucket limiter with per-second and per-minute quotas.
+///
+/// A limiter attached to a `Jikan` client is shared by every request made through it, including
+/// the ones made by entities like `Anime` or `Manga` it returned, and by every clone of it.
+#[derive(Debug)]
+pub struct RateLimiter {
+    buckets: Mutex<[Bucket; 2]>,
+}
+
+impl RateLimiter {
+    /// Constructs a new limiter allowing at most `per_second` requests per second and
+    /// `per_minute` requests per minute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::RateLimiter;
+    ///
+    /// let jikan = Jikan::new().with_rate_limiter(RateLimiter::new(2, 30));
+    /// ```
+    pub fn new(per_second: u32, per_minute: u32) -> RateLimiter {
+        let now = Instant::now();
+        RateLimiter {
+            buckets: Mutex::new([
+                Bucket::new(per_second, Duration::from_secs(1), now),
+                Bucket::new(per_minute, Duration::from_secs(60), now),
+            ]),
+        }
+    }
+
+    /// Waits until both quotas allow another request and takes it.
+    pub async fn acquire(&self) {
+        while let Some(wait) = self.try_acquire() {
+            delay_for(wait).await;
+        }
+    }
+
+    /// Takes a request if both quotas allow it, otherwise returns how long to wait before trying again.
+    fn try_acquire(&self) -> Option<Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        let now = Instant::now();
+        buckets.iter_mut().for_each(|bucket| bucket.refill(now));
+
+        let wait = buckets.iter()
+            .map(Bucket::time_to_next_token)
+            .max()
+            .unwrap_or_default();
+        if wait > Duration::from_secs(0) {
+            return Some(wait);
+        }
+
+        buckets.iter_mut().for_each(|bucket| bucket.tokens -= 1.0);
+        None
+    }
+}
+
+/// Limits of Jikan v3: 2 requests per second and 30 requests per minute.
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter::new(2, 30)
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    tokens: f64,
+    tokens_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn new(capacity: u32, period: Duration, now: Instant) -> Bucket {
+        let capacity = f64::from(capacity.max(1));
+        Bucket {
+            capacity,
+            tokens: capacity,
+            tokens_per_sec: capacity / period.as_secs_f64(),
+            last_refill: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.tokens_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    fn time_to_next_token(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.tokens_per_sec)
+        }
+    }
+}
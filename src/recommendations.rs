@@ -1,18 +1,12 @@
-use reqwest::Client;
-
 use crate::base::Resource;
 use crate::base::SourceType;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_json, Result};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_recommendations(mal_id: SourceType, http_clt: &Client) -> Result<Vec<Recommendation>> {
-    let url = format!("{}{}/recommendations", BASE_URL, mal_id.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let response: Response = serde_json::from_str(&body)?;
+pub(crate) async fn find_recommendations(mal_id: SourceType, http_clt: &HttpClient) -> Result<Vec<Recommendation>> {
+    let url = format!("{}{}/recommendations", http_clt.base_url(), mal_id.uri());
+    let body = request::get(&url, http_clt).await?;
+    let response: Response = from_json(&body)?;
 
     Ok(response.recommendations)
 }
@@ -23,7 +17,7 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Recommendation {
     pub mal_id: u32,
     pub url: Option<String>,
@@ -0,0 +1,214 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use reqwest::Client;
+use tokio::time::delay_for;
+
+use crate::cache::{Cache, CacheEntry};
+use crate::client::BASE_URL;
+use crate::error::{Error, Result};
+use crate::rate_limit::RateLimiter;
+use crate::response::{Body, HttpMeta};
+use crate::retry::{self, RetryPolicy};
+use crate::transport::{ReqwestTransport, Request, Transport};
+use crate::v4;
+
+const DEFAULT_CONCURRENCY: usize = 4;
+
+/// HTTP client shared by `Jikan` and the entities it returns.
+#[derive(Clone)]
+pub(crate) struct HttpClient {
+    transport: Arc<dyn Transport>,
+    base_url: String,
+    v4_base_url: String,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    walk_rate_limiter: Option<Arc<RateLimiter>>,
+    retry_policy: Option<RetryPolicy>,
+    cache: Option<Arc<dyn Cache>>,
+    bypass_cache: bool,
+    concurrency: usize,
+}
+
+impl HttpClient {
+    pub(crate) fn new(transport: Arc<dyn Transport>, base_url: &str) -> HttpClient {
+        HttpClient {
+            transport,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            v4_base_url: v4::BASE_URL.to_string(),
+            rate_limiter: None,
+            walk_rate_limiter: Some(Arc::new(RateLimiter::default())),
+            retry_policy: None,
+            cache: None,
+            bypass_cache: false,
+            concurrency: DEFAULT_CONCURRENCY,
+        }
+    }
+
+    pub(crate) fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> HttpClient {
+        self.rate_limiter = Some(Arc::new(rate_limiter));
+        self
+    }
+
+    /// Stops throttling franchise walks made by a client without rate limiter.
+    pub(crate) fn without_walk_rate_limiter(mut self) -> HttpClient {
+        self.walk_rate_limiter = None;
+        self
+    }
+
+    /// Returns a copy of the client to walk franchises with.
+    ///
+    /// Unless the client has a limiter already, the copy is limited by the one every walk of the client
+    /// shares, created along with the client, so concurrent walks stay within the quotas of Jikan together.
+    pub(crate) fn for_walk(&self) -> HttpClient {
+        let mut http_clt = self.clone();
+        if http_clt.rate_limiter.is_none() {
+            http_clt.rate_limiter = http_clt.walk_rate_limiter.clone();
+        }
+        http_clt
+    }
+
+    pub(crate) fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> HttpClient {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    pub(crate) fn with_cache(mut self, cache: Arc<dyn Cache>) -> HttpClient {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub(crate) fn with_v4_base_url(mut self, v4_base_url: &str) -> HttpClient {
+        self.v4_base_url = v4_base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Returns a client sharing this one's configuration that targets the v4 API.
+    pub(crate) fn v4(&self) -> HttpClient {
+        HttpClient {
+            base_url: self.v4_base_url.clone(),
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn with_concurrency(mut self, concurrency: usize) -> HttpClient {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub(crate) fn bypassing_cache(&self) -> HttpClient {
+        HttpClient {
+            bypass_cache: true,
+            ..self.clone()
+        }
+    }
+
+    pub(crate) fn cache(&self) -> Option<&dyn Cache> {
+        self.cache.as_deref()
+    }
+
+    /// Maximum number of requests sent at once by calls fetching several pages.
+    pub(crate) fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    pub(crate) fn base_url(&self) -> &str {
+        &self.base_url
+    }
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        HttpClient::new(Arc::new(ReqwestTransport::new(Client::new())), BASE_URL)
+    }
+}
+
+// Entities embed the client that fetched them, it takes no part in their equality
+impl PartialEq for HttpClient {
+    fn eq(&self, _: &HttpClient) -> bool {
+        true
+    }
+}
+
+impl Eq for HttpClient {}
+
+impl Hash for HttpClient {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl fmt::Debug for HttpClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpClient")
+            .field("base_url", &self.base_url)
+            .field("v4_base_url", &self.v4_base_url)
+            .field("rate_limiter", &self.rate_limiter)
+            .field("retry_policy", &self.retry_policy)
+            .field("cache", &self.cache.is_some())
+            .field("bypass_cache", &self.bypass_cache)
+            .field("concurrency", &self.concurrency)
+            .finish()
+    }
+}
+
+pub(crate) async fn get(url: &str, http_clt: &HttpClient) -> Result<Body> {
+    let cache = match http_clt.cache() {
+        Some(cache) => cache,
+        None => return fetch(url, http_clt).await,
+    };
+
+    if !http_clt.bypass_cache {
+        let start = Instant::now();
+        if let Some(entry) = cache.get(url) {
+            if !entry.is_expired() {
+                let http_meta = HttpMeta { status: 200, response_time: start.elapsed(), from_local_cache: true };
+                return Ok(Body { text: entry.body, http_meta });
+            }
+        }
+    }
+
+    let body = fetch(url, http_clt).await?;
+    match CacheEntry::from_body(&body) {
+        Some(entry) => cache.put(url, entry),
+        None => cache.remove(url),
+    }
+    Ok(body)
+}
+
+async fn fetch(url: &str, http_clt: &HttpClient) -> Result<Body> {
+    let mut attempt = 1;
+    loop {
+        let (error, retry_after) = match send(url, http_clt).await {
+            Ok(body) => return Ok(body),
+            Err(failure) => failure,
+        };
+        match &http_clt.retry_policy {
+            Some(policy) if policy.should_retry(&error, attempt) => {
+                delay_for(policy.delay(attempt, retry_after)).await;
+                attempt += 1;
+            }
+            _ => return Err(error),
+        }
+    }
+}
+
+async fn send(url: &str, http_clt: &HttpClient) -> std::result::Result<Body, (Error, Option<Duration>)> {
+    if let Some(rate_limiter) = &http_clt.rate_limiter {
+        rate_limiter.acquire().await;
+    }
+
+    let start = Instant::now();
+    let request = Request { url: url.to_string() };
+    let response = http_clt.transport.send(request)
+        .await
+        .map_err(|err| (err, None))?;
+    let response_time = start.elapsed();
+
+    if !(200..300).contains(&response.status) {
+        let retry_after = response.header("retry-after").and_then(retry::parse_retry_after);
+        return Err((Error::from_status(response.status, &response.body), retry_after));
+    }
+
+    let http_meta = HttpMeta { status: response.status, response_time, from_local_cache: false };
+    Ok(Body { text: response.body, http_meta })
+}
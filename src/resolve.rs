@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use crate::{anime, character, magazine, manga, person, producer};
+use crate::anime::Anime;
+use crate::base::{MALImageItem, MALRoleItem, MALTypeItem};
+use crate::character::Character;
+use crate::client::Jikan;
+use crate::error::{Error, Result};
+use crate::id::{AnimeId, CharacterId, MagazineId, MangaId, PersonId, ProducerId};
+use crate::magazine::Magazine;
+use crate::manga::Manga;
+use crate::person::Person;
+use crate::producer::Producer;
+use crate::request::HttpClient;
+
+/// Typed id of any MyAnimeList entity a reference can be resolved into.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Reference {
+    Anime(AnimeId),
+    Manga(MangaId),
+    Person(PersonId),
+    Character(CharacterId),
+    Producer(ProducerId),
+    Magazine(MagazineId),
+}
+
+impl Reference {
+    /// Parses MyAnimeList urls such as "https://myanimelist.net/anime/producer/14/Sunrise".
+    ///
+    /// Urls to other pages, e.g. genres or user profiles, return `None`.
+    pub fn from_url(url: &str) -> Option<Reference> {
+        let path = match url.split_once("://") {
+            Some((_, rest)) => rest.split_once('/').map_or("", |(_, path)| path),
+            None => url,
+        };
+        let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+        let reference = match segments.as_slice() {
+            ["anime", "producer", id, ..] | ["producer", id, ..] => Reference::Producer(ProducerId(id.parse().ok()?)),
+            ["manga", "magazine", id, ..] | ["magazine", id, ..] => Reference::Magazine(MagazineId(id.parse().ok()?)),
+            ["anime", id, ..] => Reference::Anime(AnimeId(id.parse().ok()?)),
+            ["manga", id, ..] => Reference::Manga(MangaId(id.parse().ok()?)),
+            ["people", id, ..] | ["person", id, ..] => Reference::Person(PersonId(id.parse().ok()?)),
+            ["character", id, ..] => Reference::Character(CharacterId(id.parse().ok()?)),
+            _ => return None,
+        };
+        Some(reference)
+    }
+}
+
+/// Entity fetched by resolving a `Reference`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Entity {
+    Anime(Box<Anime>),
+    Manga(Box<Manga>),
+    Person(Box<Person>),
+    Character(Box<Character>),
+    /// First page of the animes of the producer.
+    Producer(Box<Producer>),
+    /// First page of the mangas of the magazine.
+    Magazine(Box<Magazine>),
+}
+
+pub(crate) async fn resolve(reference: Reference, http_clt: &HttpClient) -> Result<Entity> {
+    let entity = match reference {
+        Reference::Anime(mal_id) => Entity::Anime(Box::new(anime::find_anime(mal_id, http_clt).await?)),
+        Reference::Manga(mal_id) => Entity::Manga(Box::new(manga::find_manga(mal_id, http_clt).await?)),
+        Reference::Person(mal_id) => Entity::Person(Box::new(person::find_person(mal_id, http_clt).await?)),
+        Reference::Character(mal_id) => Entity::Character(Box::new(character::find_character(mal_id, http_clt).await?)),
+        Reference::Producer(mal_id) => Entity::Producer(Box::new(producer::find_producer(mal_id, 1, http_clt).await?)),
+        Reference::Magazine(mal_id) => Entity::Magazine(Box::new(magazine::find_magazine(mal_id, 1, http_clt).await?)),
+    };
+    Ok(entity)
+}
+
+/// Resolves each distinct reference once, in the order they first appear.
+///
+/// A reference failing to resolve does not stop the batch, its error is kept along with it.
+pub(crate) async fn resolve_all<I>(references: I, http_clt: &HttpClient) -> Vec<(Reference, Result<Entity>)>
+    where I: IntoIterator<Item = Reference>
+{
+    let mut seen = HashSet::new();
+    let mut entities = Vec::new();
+    // References are resolved one at a time, so the batch never exceeds the rate limiter of the client
+    for reference in references {
+        if seen.insert(reference) {
+            entities.push((reference, resolve(reference, http_clt).await));
+        }
+    }
+    entities
+}
+
+async fn resolve_url(reference: Option<Reference>, url: &str, jikan: &Jikan) -> Result<Entity> {
+    match reference {
+        Some(reference) => jikan.resolve(reference).await,
+        None => Err(Error::Unresolvable(url.to_string())),
+    }
+}
+
+impl MALTypeItem {
+    /// Returns the entity the item refers to, read from its url.
+    ///
+    /// The url tells producers, magazines and genres apart, as they share the content type of
+    /// the anime and manga they list.
+    pub fn reference(&self) -> Option<Reference> {
+        Reference::from_url(&self.url)
+    }
+
+    /// Fetches the entity the item refers to.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::client::Jikan;
+    /// use jikan_rs::prelude::Entity;
+    ///
+    /// let jikan = Jikan::new();
+    /// let anime = jikan.find_anime(1).await.unwrap();
+    /// // Returns Sunrise
+    /// if let Entity::Producer(studio) = anime.studios[0].resolve(&jikan).await.unwrap() {
+    ///     println!("{}", studio.data.name);
+    /// }
+    /// # }
+    /// ```
+    pub async fn resolve(&self, jikan: &Jikan) -> Result<Entity> {
+        resolve_url(self.reference(), &self.url, jikan).await
+    }
+}
+
+impl MALImageItem {
+    /// Returns the entity the item refers to, read from its url.
+    pub fn reference(&self) -> Option<Reference> {
+        Reference::from_url(&self.url)
+    }
+
+    /// Fetches the entity the item refers to.
+    pub async fn resolve(&self, jikan: &Jikan) -> Result<Entity> {
+        resolve_url(self.reference(), &self.url, jikan).await
+    }
+}
+
+impl MALRoleItem {
+    /// Returns the entity the item refers to, read from its url.
+    pub fn reference(&self) -> Option<Reference> {
+        Reference::from_url(&self.url)
+    }
+
+    /// Fetches the entity the item refers to.
+    pub async fn resolve(&self, jikan: &Jikan) -> Result<Entity> {
+        resolve_url(self.reference(), &self.url, jikan).await
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::time::Duration;
+
+/// Information about the response an entity was built from.
+///
+/// `request_hash`, `request_cached` and `request_cache_expiry` are sent by Jikan, the remaining
+/// fields describe the HTTP exchange made by this client.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResponseMeta {
+    pub request_hash: String,
+    /// Whether Jikan served the payload from its own cache instead of scraping MyAnimeList.
+    pub request_cached: bool,
+    /// Seconds until Jikan refreshes the payload.
+    pub request_cache_expiry: u32,
+    pub status: u16,
+    pub response_time: Duration,
+    /// Whether the payload was served by the cache attached to the client, see `Jikan::with_cache`.
+    pub from_local_cache: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct HttpMeta {
+    pub(crate) status: u16,
+    pub(crate) response_time: Duration,
+    pub(crate) from_local_cache: bool,
+}
+
+/// Response metadata field of an entity, left out of its equality and hash.
+///
+/// Entities compare by their payload, so two fetches of the same resource are equal even if Jikan
+/// served one from its cache, or the client took longer to receive the other.
+#[derive(Deserialize, Serialize, Clone, Copy, Default)]
+#[serde(transparent)]
+pub(crate) struct NotCompared<T>(pub(crate) T);
+
+impl<T> PartialEq for NotCompared<T> {
+    fn eq(&self, _: &NotCompared<T>) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for NotCompared<T> {}
+
+impl<T> Hash for NotCompared<T> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<T: fmt::Debug> fmt::Debug for NotCompared<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Implemented by every entity declared with `jikan_response_entity!`.
+pub(crate) trait WithHttpMeta {
+    fn set_http_meta(&mut self, http_meta: HttpMeta);
+}
+
+/// Body of a successful response.
+pub(crate) struct Body {
+    pub(crate) text: String,
+    pub(crate) http_meta: HttpMeta,
+}
+
+impl Deref for Body {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime};
+
+use crate::error::Error;
+
+/// Policy deciding whether and when failed requests are sent again.
+///
+/// Delays grow exponentially from `base_delay` up to `max_delay`. When Jikan sends a `Retry-After`
+/// header, either as seconds or as an HTTP date, it takes precedence over the computed delay, still
+/// capped by `max_delay`. Headers in neither form are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+/// use jikan_rs::client::Jikan;
+/// use jikan_rs::RetryPolicy;
+///
+/// let retry_policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(500))
+///     .retry_on(vec![429, 503]);
+/// let jikan = Jikan::new().with_retry_policy(retry_policy);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+    retry_on: Vec<u16>,
+    honor_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// Constructs a policy making up to 3 attempts, starting with a delay of 1 second and retrying
+    /// on 429, 500, 502, 503 and 504 statuses, timeouts and connection failures.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retry_on: vec![429, 500, 502, 503, 504],
+            honor_retry_after: true,
+        }
+    }
+
+    /// Total number of attempts, including the first one.
+    pub fn max_attempts(mut self, max_attempts: u32) -> RetryPolicy {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn base_delay(mut self, base_delay: Duration) -> RetryPolicy {
+        self.base_delay = base_delay;
+        self
+    }
+
+    pub fn max_delay(mut self, max_delay: Duration) -> RetryPolicy {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Randomizes each delay between half and all of its value, so clients failing together
+    /// don't retry together.
+    pub fn jitter(mut self, jitter: bool) -> RetryPolicy {
+        self.jitter = jitter;
+        self
+    }
+
+    /// HTTP statuses considered transient.
+    pub fn retry_on(mut self, statuses: Vec<u16>) -> RetryPolicy {
+        self.retry_on = statuses;
+        self
+    }
+
+    pub fn honor_retry_after(mut self, honor_retry_after: bool) -> RetryPolicy {
+        self.honor_retry_after = honor_retry_after;
+        self
+    }
+
+    pub(crate) fn should_retry(&self, error: &Error, attempt: u32) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        match error {
+            Error::Transport(err) if err.is_timeout() || err.is_connect() => true,
+            Error::Transport(_) => false,
+            error => error.status().is_some_and(|status| self.retry_on.contains(&status)),
+        }
+    }
+
+    pub(crate) fn delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let (true, Some(retry_after)) = (self.honor_retry_after, retry_after) {
+            return retry_after.min(self.max_delay);
+        }
+
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let delay = self.base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if self.jitter {
+            delay.mul_f64(0.5 + random_fraction() / 2.0)
+        } else {
+            delay
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
+
+/// Reads a `Retry-After` header, given as a number of seconds or as an HTTP date.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    // A date already past allows retrying right away
+    Some(date.duration_since(SystemTime::now()).unwrap_or_default())
+}
+
+fn random_fraction() -> f64 {
+    let random = RandomState::new().build_hasher().finish();
+    (random >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -1,53 +1,54 @@
-use reqwest::Client;
-
 use crate::base::Resource;
 use crate::base::SourceType;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::datetime::DateTime;
+use crate::error::{Error, from_json, Result};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_reviews(mal_id: SourceType, page: u16, http_clt: &Client) -> Result<Reviews> {
-    let url = format!("{}{}/reviews/{}", BASE_URL, mal_id.uri(), page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
+pub(crate) async fn find_reviews(mal_id: SourceType, page: u16, http_clt: &HttpClient) -> Result<Reviews> {
+    let url = format!("{}{}/reviews/{}", http_clt.base_url(), mal_id.uri(), page);
+    let body = request::get(&url, http_clt).await?;
     let response = match mal_id {
         SourceType::Anime(_) => {
-            let response: ResponseReview<AnimeReviewer> = serde_json::from_str(&body)?;
+            let response: ResponseReview<AnimeReviewer> = from_json(&body)?;
             Reviews::Anime(response.reviews)
         }
         SourceType::Manga(_) => {
-            let response: ResponseReview<MangaReviewer> = serde_json::from_str(&body)?;
+            let response: ResponseReview<MangaReviewer> = from_json(&body)?;
             Reviews::Manga(response.reviews)
         }
-        _ => return Err(Box::from("There is no reviews for this type source")),
+        _ => return Err(Error::UnsupportedSource { resource: "reviews" }),
     };
     Ok(response)
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Reviews {
     Anime(Vec<Review<AnimeReviewer>>),
     Manga(Vec<Review<MangaReviewer>>),
 }
 
+jikan_result_enum!(Reviews {
+    Anime(Vec<Review<AnimeReviewer>>) => "Anime Reviews",
+    Manga(Vec<Review<MangaReviewer>>) => "Manga Reviews",
+});
+
 jikan_response_entity!(
     struct ResponseReview<T> {
         reviews: Vec<Review<T>>,
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Review<T> {
     pub mal_id: u32,
     pub url: String,
     pub helpful_count: u32,
-    pub date: Option<String>,
+    pub date: Option<DateTime>,
     pub reviewer: T,
     pub content: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnimeReviewer {
     pub url: Option<String>,
     pub image_url: Option<String>,
@@ -56,7 +57,7 @@ pub struct AnimeReviewer {
     pub scores: AnimeScores,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnimeScores {
     pub overall: u8,
     pub story: u8,
@@ -66,7 +67,7 @@ pub struct AnimeScores {
     pub enjoyment: u8,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MangaReviewer {
     pub url: Option<String>,
     pub image_url: Option<String>,
@@ -75,7 +76,7 @@ pub struct MangaReviewer {
     pub scores: MangaScores,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MangaScores {
     pub overall: u8,
     pub story: u8,
@@ -1,18 +1,12 @@
-use reqwest::Client;
-
 use crate::base::AnimeInfo;
 use crate::base::Resource;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{from_body, Result};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_schedule(schedule_on: ScheduleOn, http_clt: &Client) -> Result<Schedule> {
-    let url = format!("{}/schedule/{}", BASE_URL, schedule_on.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let schedule: Schedule = serde_json::from_str(&body)?;
+pub(crate) async fn find_schedule(schedule_on: ScheduleOn, http_clt: &HttpClient) -> Result<Schedule> {
+    let url = format!("{}/schedule/{}", http_clt.base_url(), schedule_on.uri());
+    let body = request::get(&url, http_clt).await?;
+    let schedule: Schedule = from_body(&body)?;
 
     Ok(schedule)
 }
@@ -1,6 +1,6 @@
 use crate::base::Resource;
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Copy, Debug)]
 #[uri_prefix = "/search/"]
 pub enum SearchSource {
     Anime,
@@ -9,12 +9,14 @@ pub enum SearchSource {
     Character,
 }
 
+#[derive(Debug)]
 pub enum SearchSourceType {
     Anime(AnimeType),
     Manga(MangaType),
 }
 
-#[derive(Resource)]
+#[derive(Resource, Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
 #[uri_prefix = "type="]
 pub enum AnimeType {
     TV,
@@ -23,9 +25,20 @@ pub enum AnimeType {
     Special,
     ONA,
     Music,
+    Unknown(String),
 }
 
-#[derive(Resource)]
+jikan_enum_strings!(AnimeType {
+    TV => "TV",
+    OVA => "OVA",
+    Movie => "Movie",
+    Special => "Special",
+    ONA => "ONA",
+    Music => "Music",
+});
+
+#[derive(Resource, Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
 #[uri_prefix = "type="]
 pub enum MangaType {
     Manga,
@@ -34,30 +47,58 @@ pub enum MangaType {
     Doujin,
     Manhwa,
     Manhua,
+    Unknown(String),
 }
 
+jikan_enum_strings!(MangaType {
+    Manga => "Manga",
+    Novel => "Novel",
+    OneShot => "One-shot",
+    Doujin => "Doujinshi",
+    Manhwa => "Manhwa",
+    Manhua => "Manhua",
+});
+
+#[derive(Debug)]
 pub enum SourceStatus {
     Anime(AnimeStatus),
     Manga(MangaStatus),
 }
 
-#[derive(Resource, Clone)]
+#[derive(Resource, Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
 pub enum AnimeStatus {
     Airing,
     #[rename_uri = "complete"]
     Completed,
     ToBeAired,
+    Unknown(String),
 }
 
-#[derive(Resource)]
+jikan_enum_strings!(AnimeStatus {
+    Airing => "Currently Airing",
+    Completed => "Finished Airing",
+    ToBeAired => "Not yet aired",
+});
+
+#[derive(Resource, Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
 pub enum MangaStatus {
     Publishing,
     #[rename_uri = "complete"]
     Completed,
     ToBePublished,
+    Unknown(String),
 }
 
-#[derive(Resource)]
+jikan_enum_strings!(MangaStatus {
+    Publishing => "Publishing",
+    Completed => "Finished",
+    ToBePublished => "Not yet published",
+});
+
+#[derive(Resource, Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
 #[uri_prefix = "rated="]
 pub enum Rating {
     G,
@@ -66,9 +107,20 @@ pub enum Rating {
     R17,
     R,
     Rx,
+    Unknown(String),
 }
 
-#[derive(Resource)]
+// Search results only carry the short form of the rating
+jikan_enum_strings!(Rating {
+    G => "G - All Ages" | "G",
+    Pg => "PG - Children" | "PG",
+    Pg13 => "PG-13 - Teens 13 or older" | "PG-13",
+    R17 => "R - 17+ (violence & profanity)" | "R",
+    R => "R+ - Mild Nudity" | "R+",
+    Rx => "Rx - Hentai" | "Rx",
+});
+
+#[derive(Resource, Debug)]
 #[uri_prefix = "order_by="]
 pub enum OrderBy {
     Title,
@@ -84,19 +136,64 @@ pub enum OrderBy {
     Chapters,
 }
 
-#[derive(Resource, Clone)]
+#[derive(Resource, Clone, Debug)]
 #[uri_prefix = "sort="]
 pub enum Sort {
     Ascending,
     Descending,
 }
 
+/// Material an anime is adapted from.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(from = "String", into = "String")]
+pub enum AnimeSource {
+    Original,
+    Manga,
+    FourKomaManga,
+    WebManga,
+    DigitalManga,
+    Novel,
+    LightNovel,
+    VisualNovel,
+    WebNovel,
+    Game,
+    CardGame,
+    Book,
+    PictureBook,
+    Radio,
+    Music,
+    MixedMedia,
+    Other,
+    Unknown(String),
+}
+
+jikan_enum_strings!(AnimeSource {
+    Original => "Original",
+    Manga => "Manga",
+    FourKomaManga => "4-koma manga",
+    WebManga => "Web manga",
+    DigitalManga => "Digital manga",
+    Novel => "Novel",
+    LightNovel => "Light novel",
+    VisualNovel => "Visual novel",
+    WebNovel => "Web novel",
+    Game => "Game",
+    CardGame => "Card game",
+    Book => "Book",
+    PictureBook => "Picture book",
+    Radio => "Radio",
+    Music => "Music",
+    MixedMedia => "Mixed media",
+    Other => "Other",
+});
+
+#[derive(Debug)]
 pub enum Genres {
     Anime(Vec<AnimeGenre>),
     Manga(Vec<MangaGenre>),
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 pub enum AnimeGenre {
     Action = 1,
     Adventure = 2,
@@ -143,7 +240,7 @@ pub enum AnimeGenre {
     Josei = 43,
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug)]
 pub enum MangaGenre {
     Action = 1,
     Adventure = 2,
@@ -1,38 +1,46 @@
 use percent_encoding::utf8_percent_encode;
-use reqwest::Client;
 
 use crate::base::{Date, FRAGMENT};
 use crate::base::Resource;
-use crate::client::BASE_URL;
+use crate::error::{from_body, Result};
+use crate::id::{MagazineId, ProducerId};
+use crate::request::{self, HttpClient};
 use crate::search::enums::{Genres, OrderBy, Rating, Sort, SearchSource, SourceStatus, SearchSourceType};
 use crate::search::results::SearchResultEnum;
 
 pub mod enums;
 pub mod results;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn search(query_builder: SearchQueryBuilder, http_clt: &Client) -> Result<SearchResultEnum> {
+pub(crate) async fn search(query_builder: SearchQueryBuilder, http_clt: &HttpClient) -> Result<SearchResultEnum> {
     let query = query_builder.build()?;
-    let url = format!("{}{}?{}", BASE_URL, query.source.uri(), query.query);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
+    search_page(&query, query.page, http_clt).await
+}
+
+pub(crate) async fn search_page(query: &SearchQuery, page: u16, http_clt: &HttpClient) -> Result<SearchResultEnum> {
+    let url = format!("{}{}?page={}{}", http_clt.base_url(), query.source.uri(), page, query.query);
+    let body = request::get(&url, http_clt).await?;
     let search_result = match query.source {
-        SearchSource::Anime => SearchResultEnum::Anime(serde_json::from_str(&body)?),
-        SearchSource::Manga => SearchResultEnum::Manga(serde_json::from_str(&body)?),
-        SearchSource::Person => SearchResultEnum::Person(serde_json::from_str(&body)?),
-        SearchSource::Character => SearchResultEnum::Character(serde_json::from_str(&body)?),
+        SearchSource::Anime => SearchResultEnum::Anime(from_body(&body)?),
+        SearchSource::Manga => SearchResultEnum::Manga(from_body(&body)?),
+        SearchSource::Person => SearchResultEnum::Person(from_body(&body)?),
+        SearchSource::Character => SearchResultEnum::Character(from_body(&body)?),
     };
     Ok(search_result)
 }
 
 pub struct SearchQuery {
     source: SearchSource,
+    page: u16,
     query: String,
 }
 
+impl SearchQuery {
+    pub(crate) fn page(&self) -> u16 {
+        self.page
+    }
+}
+
+#[derive(Debug)]
 pub struct SearchQueryBuilder {
     source: SearchSource,
     page: u16,
@@ -45,11 +53,11 @@ pub struct SearchQueryBuilder {
     genre: Option<Genres>,
     score: Option<u8>,
     genre_exclude: bool,
-    start_date: Option<String>,
-    end_date: Option<String>,
+    start_date: Option<Date>,
+    end_date: Option<Date>,
     limit: Option<u8>,
-    producer: Option<u32>,
-    magazine: Option<u32>,
+    producer: Option<ProducerId>,
+    magazine: Option<MagazineId>,
     letter: Option<char>,
 }
 
@@ -123,12 +131,12 @@ impl SearchQueryBuilder {
     }
 
     pub fn start_date(mut self, date: Date) -> SearchQueryBuilder {
-        self.start_date = Some(date.to_string());
+        self.start_date = Some(date);
         self
     }
 
     pub fn end_date(mut self, date: Date) -> SearchQueryBuilder {
-        self.end_date = Some(date.to_string());
+        self.end_date = Some(date);
         self
     }
 
@@ -137,13 +145,13 @@ impl SearchQueryBuilder {
         self
     }
 
-    pub fn producer(mut self, mal_id: u32) -> SearchQueryBuilder {
-        self.producer = Some(mal_id);
+    pub fn producer(mut self, mal_id: impl Into<ProducerId>) -> SearchQueryBuilder {
+        self.producer = Some(mal_id.into());
         self
     }
 
-    pub fn magazine(mut self, mal_id: u32) -> SearchQueryBuilder {
-        self.magazine = Some(mal_id);
+    pub fn magazine(mut self, mal_id: impl Into<MagazineId>) -> SearchQueryBuilder {
+        self.magazine = Some(mal_id.into());
         self
     }
 
@@ -154,7 +162,6 @@ impl SearchQueryBuilder {
 
     pub fn build(self) -> Result<SearchQuery> {
         let mut query = String::from("");
-        query = format!("{}page={}", query, self.page);
 
         if let Some(name) = self.name {
             query = format!("{}&q={}", query, name);
@@ -162,8 +169,8 @@ impl SearchQueryBuilder {
 
         if let Some(source_type) = self.source_type {
             match source_type {
-                SearchSourceType::Anime(anime_type) => query = format!("{}&status={}", query, anime_type.uri()),
-                SearchSourceType::Manga(manga_type) => query = format!("{}&status={}", query, manga_type.uri()),
+                SearchSourceType::Anime(anime_type) => query = format!("{}&{}", query, anime_type.uri()),
+                SearchSourceType::Manga(manga_type) => query = format!("{}&{}", query, manga_type.uri()),
             }
         }
 
@@ -237,6 +244,7 @@ impl SearchQueryBuilder {
         Ok(
             SearchQuery {
                 source: self.source,
+                page: self.page,
                 query,
             }
         )
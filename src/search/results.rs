@@ -1,5 +1,10 @@
 use crate::base::MALTypeItem;
+use crate::datetime::DateTime;
+use crate::id::{AnimeId, CharacterId, MangaId, PersonId};
+use crate::response::ResponseMeta;
+use crate::search::enums::{AnimeType, MangaType, Rating};
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub enum SearchResultEnum {
     Anime(SearchResult<AnimeResult>),
     Manga(SearchResult<MangaResult>),
@@ -7,6 +12,24 @@ pub enum SearchResultEnum {
     Character(SearchResult<CharacterResult>),
 }
 
+jikan_result_enum!(SearchResultEnum {
+    Anime(SearchResult<AnimeResult>) => "Anime Search Results",
+    Manga(SearchResult<MangaResult>) => "Manga Search Results",
+    Person(SearchResult<PersonResult>) => "Person Search Results",
+    Character(SearchResult<CharacterResult>) => "Character Search Results",
+});
+
+impl SearchResultEnum {
+    pub fn meta(&self) -> ResponseMeta {
+        match self {
+            SearchResultEnum::Anime(result) => result.meta(),
+            SearchResultEnum::Manga(result) => result.meta(),
+            SearchResultEnum::Person(result) => result.meta(),
+            SearchResultEnum::Character(result) => result.meta(),
+        }
+    }
+}
+
 jikan_response_entity!(
     pub struct SearchResult<T> {
         pub results: Vec<T>,
@@ -14,54 +37,54 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct AnimeResult {
-    pub mal_id: u32,
+    pub mal_id: AnimeId,
     pub url: String,
     pub image_url: Option<String>,
     pub title: String,
     pub airing: bool,
     pub synopsis: Option<String>,
     #[serde(rename = "type")]
-    pub anime_type: String,
+    pub anime_type: AnimeType,
     pub episodes: Option<u16>,
     pub score: Option<f32>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<DateTime>,
+    pub end_date: Option<DateTime>,
     pub members: Option<u32>,
-    pub rated: Option<String>,
+    pub rated: Option<Rating>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct MangaResult {
-    pub mal_id: u32,
+    pub mal_id: MangaId,
     pub url: String,
     pub image_url: Option<String>,
     pub title: String,
     pub publishing: bool,
     pub synopsis: Option<String>,
     #[serde(rename = "type")]
-    pub manga_type: String,
+    pub manga_type: MangaType,
     pub chapter: Option<u16>,
     pub volumes: Option<u16>,
     pub score: Option<f32>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<DateTime>,
+    pub end_date: Option<DateTime>,
     pub members: Option<u32>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PersonResult {
-    pub mal_id: u32,
+    pub mal_id: PersonId,
     pub name: String,
     pub url: String,
     pub image_url: String,
     pub alternative_names: Vec<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CharacterResult {
-    pub mal_id: u32,
+    pub mal_id: CharacterId,
     pub name: String,
     pub url: String,
     pub image_url: String,
@@ -1,16 +1,10 @@
-use reqwest::Client;
+use crate::error::{from_json, Result};
+use crate::request::{self, HttpClient};
 
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_season_archives(http_clt: &Client) -> Result<Vec<ArchivedSeason>> {
-    let url = format!("{}/season/archive", BASE_URL);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let response: Response = serde_json::from_str(&body)?;
+pub(crate) async fn find_season_archives(http_clt: &HttpClient) -> Result<Vec<ArchivedSeason>> {
+    let url = format!("{}/season/archive", http_clt.base_url());
+    let body = request::get(&url, http_clt).await?;
+    let response: Response = from_json(&body)?;
 
     Ok(response.archive)
 }
@@ -21,7 +15,7 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ArchivedSeason {
     pub year: u16,
     pub seasons: Vec<String>,
@@ -1,24 +1,18 @@
-use reqwest::Client;
-
 use crate::base::{AnimeInfo, Resource};
-use crate::client::BASE_URL;
+use crate::error::{from_body, Result};
+use crate::request::{self, HttpClient};
 
 pub mod archive;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_season(season: Season, http_clt: &Client) -> Result<SeasonResult> {
-    let url = format!("{}/season/{}", BASE_URL, season.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
-    let season_result: SeasonResult = serde_json::from_str(&body)?;
+pub(crate) async fn find_season(season: Season, http_clt: &HttpClient) -> Result<SeasonResult> {
+    let url = format!("{}/season/{}", http_clt.base_url(), season.uri());
+    let body = request::get(&url, http_clt).await?;
+    let season_result: SeasonResult = from_body(&body)?;
 
     Ok(season_result)
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Season {
     Winter(u16),
     Fall(u16),
@@ -1,31 +1,41 @@
-use reqwest::Client;
-
 use crate::base::Resource;
 use crate::base::SourceType;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::error::{Error, from_body, Result};
+use crate::request::{self, HttpClient};
+use crate::response::ResponseMeta;
 
-pub(crate) async fn find_stats(mal_id: SourceType, http_clt: &Client) -> Result<Stats> {
-    let url = format!("{}{}/stats", BASE_URL, mal_id.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
+pub(crate) async fn find_stats(mal_id: SourceType, http_clt: &HttpClient) -> Result<Stats> {
+    let url = format!("{}{}/stats", http_clt.base_url(), mal_id.uri());
+    let body = request::get(&url, http_clt).await?;
     let stats = match mal_id {
-        SourceType::Anime(_) => Stats::Anime(serde_json::from_str(&body)?),
-        SourceType::Manga(_) => Stats::Manga(serde_json::from_str(&body)?),
-        _ => return Err(Box::from("There is no stats for this type source")),
+        SourceType::Anime(_) => Stats::Anime(from_body(&body)?),
+        SourceType::Manga(_) => Stats::Manga(from_body(&body)?),
+        _ => return Err(Error::UnsupportedSource { resource: "stats" }),
     };
 
     Ok(stats)
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub enum Stats {
     Anime(AnimeStats),
     Manga(MangaStats),
 }
 
+jikan_result_enum!(Stats {
+    Anime(AnimeStats) => "Anime Stats",
+    Manga(MangaStats) => "Manga Stats",
+});
+
+impl Stats {
+    pub fn meta(&self) -> ResponseMeta {
+        match self {
+            Stats::Anime(stats) => stats.meta(),
+            Stats::Manga(stats) => stats.meta(),
+        }
+    }
+}
+
 jikan_response_entity!(
     pub struct AnimeStats {
         pub watching: u32,
@@ -50,7 +60,7 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct Score {
     #[serde(rename = "1")]
     pub one: ScoreStats,
@@ -74,7 +84,7 @@ pub struct Score {
     pub ten: ScoreStats,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct ScoreStats {
     pub votes: u32,
     pub percentage: f32,
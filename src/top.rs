@@ -1,32 +1,29 @@
-use reqwest::Client;
-
 use crate::base::MALTypeItem;
 use crate::base::Resource;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::datetime::DateTime;
+use crate::error::{from_json, Result};
+use crate::id::{AnimeId, CharacterId, MangaId, PersonId};
+use crate::request::{self, HttpClient};
+use crate::search::enums::{AnimeType, MangaType};
 
-pub(crate) async fn find_top(top: Top, http_clt: &Client) -> Result<TopResult> {
-    let url = format!("{}/top/{}", BASE_URL, top.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
+pub(crate) async fn find_top(top: Top, http_clt: &HttpClient) -> Result<TopResult> {
+    let url = format!("{}/top/{}", http_clt.base_url(), top.uri());
+    let body = request::get(&url, http_clt).await?;
     let top_result = match top {
         Top::Anime { page: _, subtype: _ } => {
-            let response: Response<RankedAnime> = serde_json::from_str(&body)?;
+            let response: Response<RankedAnime> = from_json(&body)?;
             TopResult::Anime(response.top)
         }
         Top::Manga { page: _, subtype: _ } => {
-            let response: Response<RankedManga> = serde_json::from_str(&body)?;
+            let response: Response<RankedManga> = from_json(&body)?;
             TopResult::Manga(response.top)
         }
         Top::Character(_) => {
-            let response: Response<RankedCharacter> = serde_json::from_str(&body)?;
+            let response: Response<RankedCharacter> = from_json(&body)?;
             TopResult::Character(response.top)
         }
         Top::People(_) => {
-            let response: Response<RankedPerson> = serde_json::from_str(&body)?;
+            let response: Response<RankedPerson> = from_json(&body)?;
             TopResult::People(response.top)
         }
     };
@@ -34,6 +31,7 @@ pub(crate) async fn find_top(top: Top, http_clt: &Client) -> Result<TopResult> {
     Ok(top_result)
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Top {
     Anime { page: u16, subtype: TopAnimeSubtype },
     Manga { page: u16, subtype: TopMangaSubtype },
@@ -41,6 +39,24 @@ pub enum Top {
     People(u16),
 }
 
+impl Top {
+    pub(crate) fn page(&self) -> u16 {
+        match self {
+            Top::Anime { page, .. } | Top::Manga { page, .. } => *page,
+            Top::Character(page) | Top::People(page) => *page,
+        }
+    }
+
+    pub(crate) fn with_page(self, page: u16) -> Top {
+        match self {
+            Top::Anime { subtype, .. } => Top::Anime { page, subtype },
+            Top::Manga { subtype, .. } => Top::Manga { page, subtype },
+            Top::Character(_) => Top::Character(page),
+            Top::People(_) => Top::People(page),
+        }
+    }
+}
+
 impl Resource for Top {
     fn uri(&self) -> String {
         match self {
@@ -52,7 +68,7 @@ impl Resource for Top {
     }
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TopAnimeSubtype {
     #[rename_uri = ""]
     All,
@@ -68,7 +84,7 @@ pub enum TopAnimeSubtype {
     ByFavorite,
 }
 
-#[derive(Resource)]
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum TopMangaSubtype {
     #[rename_uri = ""]
     All,
@@ -84,6 +100,7 @@ pub enum TopMangaSubtype {
     ByFavorite,
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub enum TopResult {
     Anime(Vec<RankedAnime>),
     Manga(Vec<RankedManga>),
@@ -91,41 +108,48 @@ pub enum TopResult {
     People(Vec<RankedPerson>),
 }
 
-#[derive(Deserialize, Debug)]
+jikan_result_enum!(TopResult {
+    Anime(Vec<RankedAnime>) => "Top Anime",
+    Manga(Vec<RankedManga>) => "Top Manga",
+    Character(Vec<RankedCharacter>) => "Top Characters",
+    People(Vec<RankedPerson>) => "Top People",
+});
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct RankedAnime {
-    pub mal_id: u32,
+    pub mal_id: AnimeId,
     pub rank: u32,
     pub title: String,
     pub url: String,
     pub image_url: Option<String>,
     #[serde(rename = "type")]
-    pub anime_type: String,
+    pub anime_type: AnimeType,
     pub episodes: Option<u16>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<DateTime>,
+    pub end_date: Option<DateTime>,
     pub members: Option<u32>,
     pub score: Option<f32>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct RankedManga {
-    pub mal_id: u32,
+    pub mal_id: MangaId,
     pub rank: u32,
     pub title: String,
     pub url: String,
     pub image_url: Option<String>,
     #[serde(rename = "type")]
-    pub anime_type: String,
+    pub manga_type: MangaType,
     pub volumes: Option<u16>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
+    pub start_date: Option<DateTime>,
+    pub end_date: Option<DateTime>,
     pub members: Option<u32>,
     pub score: Option<f32>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RankedCharacter {
-    pub mal_id: u32,
+    pub mal_id: CharacterId,
     pub rank: u32,
     pub title: String,
     pub name_kanji: Option<String>,
@@ -136,22 +160,20 @@ pub struct RankedCharacter {
     pub mangaography: Vec<MALTypeItem>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct RankedPerson {
-    pub mal_id: u32,
+    pub mal_id: PersonId,
     pub rank: u32,
     pub title: String,
     pub name_kanji: Option<String>,
     pub url: String,
     pub favorites: u32,
     pub image_url: Option<String>,
-    pub birthday: Option<String>,
+    pub birthday: Option<DateTime>,
 }
 
-#[derive(Deserialize, Debug)]
-struct Response<T> {
-    request_hash: String,
-    request_cached: bool,
-    request_cache_expiry: u32,
-    top: Vec<T>,
-}
\ No newline at end of file
+jikan_response_entity!(
+    struct Response<T> {
+        top: Vec<T>,
+    }
+);
\ No newline at end of file
@@ -0,0 +1,165 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use reqwest::Client;
+
+use crate::error::Result;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Sends requests made by the `Jikan` client.
+///
+/// Implement this trait to drive the client through something other than `reqwest`, such as
+/// recorded responses in tests.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>>;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Request {
+    pub url: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    /// Header names are lowercase.
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl Response {
+    pub fn new(status: u16, body: &str) -> Response {
+        Response {
+            status,
+            headers: HashMap::new(),
+            body: body.to_string(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Response {
+        self.headers.insert(name.to_lowercase(), value.to_string());
+        self
+    }
+
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+}
+
+/// Transport sending requests over the network with `reqwest`, used by default.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> ReqwestTransport {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(async move {
+            let response = self.client.get(&request.url).send()
+                .await?;
+            let status = response.status().as_u16();
+            let headers = response.headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    value.to_str().ok().map(|value| (name.as_str().to_string(), value.to_string()))
+                })
+                .collect();
+            let body = response.text()
+                .await?;
+
+            Ok(Response { status, headers, body })
+        })
+    }
+}
+
+/// In-memory transport answering with registered responses, so the client can be exercised offline.
+///
+/// Responses are matched by the end of the requested URL. When several responses are registered for
+/// the same route they are served in order, and the last one is repeated. Requests without a matching
+/// route are answered with the 404 body Jikan would send.
+///
+/// # Examples
+///
+/// ```
+/// # async fn run() {
+/// use std::sync::Arc;
+/// use jikan_rs::client::Jikan;
+/// use jikan_rs::transport::{FixtureTransport, Response};
+///
+/// let fixtures = Arc::new(FixtureTransport::new());
+/// fixtures.route("/anime/1/moreinfo", Response::new(200, r#"{
+///     "request_hash": "hash", "request_cached": false, "request_cache_expiry": 0, "moreinfo": null
+/// }"#));
+///
+/// let jikan = Jikan::builder()
+///     .transport(fixtures.clone())
+///     .build()
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Default)]
+pub struct FixtureTransport {
+    routes: Mutex<Vec<(String, VecDeque<Response>)>>,
+    requests: Mutex<Vec<Request>>,
+}
+
+impl FixtureTransport {
+    pub fn new() -> FixtureTransport {
+        FixtureTransport::default()
+    }
+
+    /// Registers a response for URLs ending with `route`.
+    pub fn route(&self, route: &str, response: Response) {
+        let mut routes = self.routes.lock().unwrap();
+        match routes.iter_mut().find(|(path, _)| path == route) {
+            Some((_, responses)) => responses.push_back(response),
+            None => routes.push((route.to_string(), VecDeque::from(vec![response]))),
+        }
+    }
+
+    /// Requests received so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn respond(&self, request: &Request) -> Response {
+        let mut routes = self.routes.lock().unwrap();
+        let url = request.url.as_str();
+        let responses = routes.iter_mut()
+            .filter(|(route, _)| url.ends_with(route.as_str()))
+            .max_by_key(|(route, _)| route.len())
+            .map(|(_, responses)| responses);
+        match responses {
+            Some(responses) if responses.len() > 1 => responses.pop_front().unwrap(),
+            Some(responses) => responses[0].clone(),
+            None => Response::new(404, r#"{"status":404,"type":"BadResponseException","message":"Resource does not exist","error":null}"#),
+        }
+    }
+}
+
+impl Transport for FixtureTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        let response = self.respond(&request);
+        self.requests.lock().unwrap().push(request);
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+impl fmt::Debug for FixtureTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixtureTransport")
+            .field("routes", &self.routes.lock().unwrap().len())
+            .field("requests", &self.requests.lock().unwrap().len())
+            .finish()
+    }
+}
@@ -1,8 +1,8 @@
 use percent_encoding::utf8_percent_encode;
-use reqwest::Client;
 
 use crate::base::{FRAGMENT, Resource};
-use crate::client::BASE_URL;
+use crate::error::{from_body, from_json, Result};
+use crate::request::{self, HttpClient};
 use crate::search::enums::Sort;
 use crate::user::enums::{AnimeListQuery, HistorySource, MangaListQuery};
 use crate::user::results::{AnimeListResponse, FriendResponse, HistoryResponse, MangaListResponse, UserResultEnum};
@@ -10,30 +10,25 @@ use crate::user::results::{AnimeListResponse, FriendResponse, HistoryResponse, M
 pub mod enums;
 pub mod results;
 
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
-
-pub(crate) async fn find_user(username: &str, user_info: UserInfo, http_clt: &Client) -> Result<UserResultEnum> {
-    let url = format!("{}/user/{}{}", BASE_URL, username, user_info.uri());
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
+pub(crate) async fn find_user(username: &str, user_info: UserInfo, http_clt: &HttpClient) -> Result<UserResultEnum> {
+    let url = format!("{}/user/{}{}", http_clt.base_url(), username, user_info.uri());
+    let body = request::get(&url, http_clt).await?;
     let user_result = match user_info {
-        UserInfo::Profile => UserResultEnum::Profile(serde_json::from_str(&body)?),
+        UserInfo::Profile => UserResultEnum::Profile(from_body(&body)?),
         UserInfo::History { source: _ } => {
-            let response: HistoryResponse = serde_json::from_str(&body)?;
+            let response: HistoryResponse = from_json(&body)?;
             UserResultEnum::History(response.history)
         }
         UserInfo::Friends { page: _ } => {
-            let response: FriendResponse = serde_json::from_str(&body)?;
+            let response: FriendResponse = from_json(&body)?;
             UserResultEnum::Friends(response.friends)
         }
         UserInfo::Animelist { query: _ } => {
-            let response: AnimeListResponse = serde_json::from_str(&body)?;
+            let response: AnimeListResponse = from_json(&body)?;
             UserResultEnum::AnimeList(response.anime)
         }
         UserInfo::Mangalist { query: _ } => {
-            let response: MangaListResponse = serde_json::from_str(&body)?;
+            let response: MangaListResponse = from_json(&body)?;
             UserResultEnum::MangaList(response.manga)
         }
     };
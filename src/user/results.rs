@@ -1,7 +1,10 @@
-use serde::de;
-
 use crate::base::{MALImageItem, MALTypeItem};
+use crate::datetime::DateTime;
+use crate::id::{AnimeId, MangaId};
+use crate::search::enums::{AnimeType, MangaType, Rating};
 
+#[allow(clippy::large_enum_variant)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub enum UserResultEnum {
     Profile(Profile),
     History(Vec<HistoryItem>),
@@ -10,17 +13,25 @@ pub enum UserResultEnum {
     MangaList(Vec<MangaListEntry>),
 }
 
+jikan_result_enum!(UserResultEnum {
+    Profile(Profile) => "User Profile",
+    History(Vec<HistoryItem>) => "User History",
+    Friends(Vec<Friend>) => "User Friends",
+    AnimeList(Vec<AnimeListEntry>) => "User Anime List",
+    MangaList(Vec<MangaListEntry>) => "User Manga List",
+});
+
 jikan_response_entity!(
     pub struct Profile {
         pub user_id: u32,
         pub username: String,
         pub url: String,
         pub image_url: Option<String>,
-        pub last_online: String,
+        pub last_online: DateTime,
         pub gender: Option<String>,
-        pub birthday: Option<String>,
+        pub birthday: Option<DateTime>,
         pub location: Option<String>,
-        pub joined: String,
+        pub joined: DateTime,
         pub anime_stats: UserAnimeStats,
         pub manga_stats: UserMangaStats,
         pub favorites: Favorites,
@@ -28,7 +39,7 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct UserAnimeStats {
     pub days_watched: f32,
     pub mean_score: f32,
@@ -42,7 +53,7 @@ pub struct UserAnimeStats {
     pub episodes_watched: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct UserMangaStats {
     pub days_read: f32,
     pub mean_score: f32,
@@ -56,7 +67,7 @@ pub struct UserMangaStats {
     pub volumes_read: u64,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Favorites {
     pub anime: Vec<MALImageItem>,
     pub manga: Vec<MALImageItem>,
@@ -70,11 +81,11 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct HistoryItem {
     pub meta: MALTypeItem,
     pub increment: u64,
-    pub date: String,
+    pub date: DateTime,
 }
 
 jikan_response_entity!(
@@ -83,13 +94,13 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Friend {
     pub username: String,
     pub url: String,
     pub image_url: Option<String>,
-    pub last_online: Option<String>,
-    pub friends_since: Option<String>,
+    pub last_online: Option<DateTime>,
+    pub friends_since: Option<DateTime>,
 }
 
 jikan_response_entity!(
@@ -98,31 +109,31 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnimeListEntry {
-    pub mal_id: u32,
+    pub mal_id: AnimeId,
     pub title: String,
     pub url: String,
     pub image_url: Option<String>,
     pub video_url: Option<String>,
     #[serde(rename = "type")]
-    pub anime_type: String,
-    #[serde(deserialize_with = "define_status")]
-    pub watching_status: bool,
+    pub anime_type: AnimeType,
+    /// MyAnimeList code of the list status: 1 watching, 2 completed, 3 on hold, 4 dropped, 6 plan to watch.
+    pub watching_status: u8,
     pub score: u8,
     pub watched_episodes: u32,
     pub total_episodes: u32,
-    #[serde(deserialize_with = "define_status")]
-    pub airing_status: bool,
+    /// MyAnimeList code of the airing status: 1 airing, 2 finished, 3 not yet aired.
+    pub airing_status: u8,
     pub has_episode_video: bool,
     pub has_promo_video: bool,
     pub has_video: bool,
     pub is_rewatching: bool,
-    pub rating: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
-    pub watch_start_date: Option<String>,
-    pub watch_end_date: Option<String>,
+    pub rating: Option<Rating>,
+    pub start_date: Option<DateTime>,
+    pub end_date: Option<DateTime>,
+    pub watch_start_date: Option<DateTime>,
+    pub watch_end_date: Option<DateTime>,
     pub days: Option<u32>,
     pub priority: Option<String>,
     pub added_to_list: bool,
@@ -134,38 +145,30 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MangaListEntry {
-    pub mal_id: u32,
+    pub mal_id: MangaId,
     pub title: String,
     pub url: String,
     pub image_url: Option<String>,
     #[serde(rename = "type")]
-    pub manga_type: String,
-    #[serde(deserialize_with = "define_status")]
-    pub reading_status: bool,
+    pub manga_type: MangaType,
+    /// MyAnimeList code of the list status: 1 reading, 2 completed, 3 on hold, 4 dropped, 6 plan to read.
+    pub reading_status: u8,
     pub score: u8,
     pub read_chapters: u32,
     pub total_chapters: u32,
     pub read_volumes: u32,
     pub total_volumes: u32,
-    #[serde(deserialize_with = "define_status")]
-    pub publishing_status: bool,
+    /// MyAnimeList code of the publishing status: 1 publishing, 2 finished, 3 not yet published.
+    pub publishing_status: u8,
     pub is_rereading: bool,
     pub rating: Option<String>,
-    pub start_date: Option<String>,
-    pub end_date: Option<String>,
-    pub read_start_date: Option<String>,
-    pub read_end_date: Option<String>,
+    pub start_date: Option<DateTime>,
+    pub end_date: Option<DateTime>,
+    pub read_start_date: Option<DateTime>,
+    pub read_end_date: Option<DateTime>,
     pub days: Option<u32>,
     pub priority: Option<String>,
     pub added_to_list: bool,
 }
-
-fn define_status<'de, D>(deserializer: D) -> Result<bool, D::Error>
-    where
-        D: de::Deserializer<'de>,
-{
-    let status: u8 = de::Deserialize::deserialize(deserializer)?;
-    if status == 1 { Ok(true) } else { Ok(false) }
-}
@@ -1,37 +1,38 @@
-use reqwest::Client;
-
 use crate::base::{AnimeStatusForUser, MangaStatusForUser, SourceType};
 use crate::base::Resource;
-use crate::client::BASE_URL;
-
-type Result<T> = std::result::Result<T, Box<dyn std::error::Error + Send + Sync>>;
+use crate::datetime::DateTime;
+use crate::error::{Error, from_json, Result};
+use crate::request::{self, HttpClient};
 
-pub(crate) async fn find_user_updates(mal_id: SourceType, page: u16, http_clt: &Client) -> Result<UserUpdates> {
-    let url = format!("{}{}/userupdates/{}", BASE_URL, mal_id.uri(), page);
-    let body = http_clt.get(&url).send()
-        .await?
-        .text()
-        .await?;
+pub(crate) async fn find_user_updates(mal_id: SourceType, page: u16, http_clt: &HttpClient) -> Result<UserUpdates> {
+    let url = format!("{}{}/userupdates/{}", http_clt.base_url(), mal_id.uri(), page);
+    let body = request::get(&url, http_clt).await?;
     let user_updates = match mal_id {
         SourceType::Anime(_) => {
-            let user_updates: AnimeUserUpdatesResponse = serde_json::from_str(&body)?;
+            let user_updates: AnimeUserUpdatesResponse = from_json(&body)?;
             UserUpdates::Anime(user_updates.users)
         }
         SourceType::Manga(_) => {
-            let user_updates: MangaUserUpdatesResponse = serde_json::from_str(&body)?;
+            let user_updates: MangaUserUpdatesResponse = from_json(&body)?;
             UserUpdates::Manga(user_updates.users)
         }
-        _ => return Err(Box::from("There is no user updates for this type source")),
+        _ => return Err(Error::UnsupportedSource { resource: "user updates" }),
     };
 
     Ok(user_updates)
 }
 
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum UserUpdates {
     Anime(Vec<AnimeUserUpdate>),
     Manga(Vec<MangaUserUpdate>),
 }
 
+jikan_result_enum!(UserUpdates {
+    Anime(Vec<AnimeUserUpdate>) => "Anime User Updates",
+    Manga(Vec<MangaUserUpdate>) => "Manga User Updates",
+});
+
 jikan_response_entity!(
     struct AnimeUserUpdatesResponse {
         users: Vec<AnimeUserUpdate>,
@@ -44,7 +45,7 @@ jikan_response_entity!(
     }
 );
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnimeUserUpdate {
     pub username: String,
     pub url: Option<String>,
@@ -53,10 +54,10 @@ pub struct AnimeUserUpdate {
     pub status: Option<AnimeStatusForUser>,
     pub episodes_seen: Option<u16>,
     pub episodes_total: Option<u16>,
-    pub date: Option<String>,
+    pub date: Option<DateTime>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MangaUserUpdate {
     pub username: String,
     pub url: Option<String>,
@@ -67,5 +68,5 @@ pub struct MangaUserUpdate {
     pub volumes_total: Option<u16>,
     pub chapters_read: Option<u16>,
     pub chapters_total: Option<u16>,
-    pub date: Option<String>,
+    pub date: Option<DateTime>,
 }
\ No newline at end of file
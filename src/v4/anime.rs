@@ -0,0 +1,65 @@
+use crate::error::Result;
+use crate::id::AnimeId;
+use crate::request::HttpClient;
+use crate::v4::common::{Broadcast, DateRange, Images, MalUrl, Title, Trailer};
+
+pub(crate) async fn find_anime(mal_id: AnimeId, http_clt: &HttpClient) -> Result<Anime> {
+    let url = format!("{}/anime/{}", http_clt.base_url(), mal_id);
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Anime {
+    pub mal_id: AnimeId,
+    pub url: String,
+    #[serde(default)]
+    pub images: Images,
+    #[serde(default)]
+    pub trailer: Trailer,
+    #[serde(default)]
+    pub approved: bool,
+    #[serde(default)]
+    pub titles: Vec<Title>,
+    pub title: String,
+    pub title_english: Option<String>,
+    pub title_japanese: Option<String>,
+    #[serde(default)]
+    pub title_synonyms: Vec<String>,
+    #[serde(rename = "type")]
+    pub anime_type: Option<String>,
+    pub source: Option<String>,
+    pub episodes: Option<u32>,
+    pub status: Option<String>,
+    #[serde(default)]
+    pub airing: bool,
+    #[serde(default)]
+    pub aired: DateRange,
+    pub duration: Option<String>,
+    pub rating: Option<String>,
+    pub score: Option<f32>,
+    pub scored_by: Option<u32>,
+    pub rank: Option<u32>,
+    pub popularity: Option<u32>,
+    pub members: Option<u32>,
+    pub favorites: Option<u32>,
+    pub synopsis: Option<String>,
+    pub background: Option<String>,
+    pub season: Option<String>,
+    pub year: Option<u16>,
+    #[serde(default)]
+    pub broadcast: Broadcast,
+    #[serde(default)]
+    pub producers: Vec<MalUrl>,
+    #[serde(default)]
+    pub licensors: Vec<MalUrl>,
+    #[serde(default)]
+    pub studios: Vec<MalUrl>,
+    #[serde(default)]
+    pub genres: Vec<MalUrl>,
+    #[serde(default)]
+    pub explicit_genres: Vec<MalUrl>,
+    #[serde(default)]
+    pub themes: Vec<MalUrl>,
+    #[serde(default)]
+    pub demographics: Vec<MalUrl>,
+}
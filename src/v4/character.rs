@@ -0,0 +1,23 @@
+use crate::error::Result;
+use crate::id::CharacterId;
+use crate::request::HttpClient;
+use crate::v4::common::Images;
+
+pub(crate) async fn find_character(mal_id: CharacterId, http_clt: &HttpClient) -> Result<Character> {
+    let url = format!("{}/characters/{}", http_clt.base_url(), mal_id);
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Character {
+    pub mal_id: CharacterId,
+    pub url: String,
+    #[serde(default)]
+    pub images: Images,
+    pub name: String,
+    pub name_kanji: Option<String>,
+    #[serde(default)]
+    pub nicknames: Vec<String>,
+    pub favorites: Option<u32>,
+    pub about: Option<String>,
+}
@@ -0,0 +1,23 @@
+use crate::datetime::DateTime;
+use crate::error::Result;
+use crate::id::ClubId;
+use crate::request::HttpClient;
+use crate::v4::common::Images;
+
+pub(crate) async fn find_club(mal_id: ClubId, http_clt: &HttpClient) -> Result<Club> {
+    let url = format!("{}/clubs/{}", http_clt.base_url(), mal_id);
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Club {
+    pub mal_id: ClubId,
+    pub name: String,
+    pub url: String,
+    #[serde(default)]
+    pub images: Images,
+    pub members: Option<u32>,
+    pub category: Option<String>,
+    pub created: Option<DateTime>,
+    pub access: Option<String>,
+}
@@ -0,0 +1,92 @@
+use crate::datetime::DateTime;
+
+/// Pagination details of a v4 list response.
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Pagination {
+    #[serde(default)]
+    pub last_visible_page: u32,
+    #[serde(default)]
+    pub has_next_page: bool,
+    pub current_page: Option<u32>,
+    pub items: Option<PaginationItems>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct PaginationItems {
+    pub count: u32,
+    pub total: u32,
+    pub per_page: u32,
+}
+
+/// A page of a v4 list endpoint.
+#[derive(Serialize, Debug)]
+pub struct PaginatedResult<T> {
+    pub data: Vec<T>,
+    pub pagination: Pagination,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Images {
+    pub jpg: Option<ImageUrls>,
+    pub webp: Option<ImageUrls>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ImageUrls {
+    pub image_url: Option<String>,
+    pub small_image_url: Option<String>,
+    pub large_image_url: Option<String>,
+}
+
+/// Reference to another MyAnimeList entry.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MalUrl {
+    pub mal_id: u32,
+    #[serde(rename = "type")]
+    pub mal_type: Option<String>,
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Title {
+    #[serde(rename = "type")]
+    pub title_type: String,
+    pub title: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Trailer {
+    pub youtube_id: Option<String>,
+    pub url: Option<String>,
+    pub embed_url: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    pub from: Option<DateTime>,
+    pub to: Option<DateTime>,
+    pub prop: Option<DateRangeProp>,
+    pub string: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct DateRangeProp {
+    pub from: DateProp,
+    pub to: DateProp,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct DateProp {
+    pub day: Option<u8>,
+    pub month: Option<u8>,
+    pub year: Option<u16>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Broadcast {
+    pub day: Option<String>,
+    pub time: Option<String>,
+    pub timezone: Option<String>,
+    pub string: Option<String>,
+}
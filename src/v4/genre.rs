@@ -0,0 +1,15 @@
+use crate::error::Result;
+use crate::request::HttpClient;
+
+pub(crate) async fn find_genres(source: &str, http_clt: &HttpClient) -> Result<Vec<Genre>> {
+    let url = format!("{}/genres/{}", http_clt.base_url(), source);
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Genre {
+    pub mal_id: u32,
+    pub name: String,
+    pub url: String,
+    pub count: Option<u32>,
+}
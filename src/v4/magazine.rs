@@ -0,0 +1,17 @@
+use crate::error::Result;
+use crate::id::MagazineId;
+use crate::request::HttpClient;
+use crate::v4::common::PaginatedResult;
+
+pub(crate) async fn find_magazines(page: u32, http_clt: &HttpClient) -> Result<PaginatedResult<Magazine>> {
+    let url = format!("{}/magazines?page={}", http_clt.base_url(), page);
+    super::get_page(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Magazine {
+    pub mal_id: MagazineId,
+    pub name: String,
+    pub url: String,
+    pub count: Option<u32>,
+}
@@ -0,0 +1,55 @@
+use crate::error::Result;
+use crate::id::MangaId;
+use crate::request::HttpClient;
+use crate::v4::common::{DateRange, Images, MalUrl, Title};
+
+pub(crate) async fn find_manga(mal_id: MangaId, http_clt: &HttpClient) -> Result<Manga> {
+    let url = format!("{}/manga/{}", http_clt.base_url(), mal_id);
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Manga {
+    pub mal_id: MangaId,
+    pub url: String,
+    #[serde(default)]
+    pub images: Images,
+    #[serde(default)]
+    pub approved: bool,
+    #[serde(default)]
+    pub titles: Vec<Title>,
+    pub title: String,
+    pub title_english: Option<String>,
+    pub title_japanese: Option<String>,
+    #[serde(default)]
+    pub title_synonyms: Vec<String>,
+    #[serde(rename = "type")]
+    pub manga_type: Option<String>,
+    pub chapters: Option<u32>,
+    pub volumes: Option<u32>,
+    pub status: Option<String>,
+    #[serde(default)]
+    pub publishing: bool,
+    #[serde(default)]
+    pub published: DateRange,
+    pub score: Option<f32>,
+    pub scored_by: Option<u32>,
+    pub rank: Option<u32>,
+    pub popularity: Option<u32>,
+    pub members: Option<u32>,
+    pub favorites: Option<u32>,
+    pub synopsis: Option<String>,
+    pub background: Option<String>,
+    #[serde(default)]
+    pub authors: Vec<MalUrl>,
+    #[serde(default)]
+    pub serializations: Vec<MalUrl>,
+    #[serde(default)]
+    pub genres: Vec<MalUrl>,
+    #[serde(default)]
+    pub explicit_genres: Vec<MalUrl>,
+    #[serde(default)]
+    pub themes: Vec<MalUrl>,
+    #[serde(default)]
+    pub demographics: Vec<MalUrl>,
+}
@@ -0,0 +1,242 @@
+//! Client for the [Jikan v4 API](https://docs.api.jikan.moe).
+//!
+//! It lives alongside the v3 client so applications can migrate endpoint by endpoint. Both
+//! clients share the same transport, rate limiter, retry policy and cache when the v4 client is
+//! obtained through `client::Jikan::v4`.
+
+use serde::de::DeserializeOwned;
+
+use crate::error::{from_json, Result};
+use crate::id::{AnimeId, CharacterId, ClubId, MangaId, PersonId, ProducerId};
+use crate::request::{self, HttpClient};
+use crate::schedule::ScheduleOn;
+use crate::season::Season;
+
+pub use self::anime::Anime;
+pub use self::character::Character;
+pub use self::club::Club;
+pub use self::common::{Broadcast, DateProp, DateRange, DateRangeProp, ImageUrls, Images, MalUrl, Pagination,
+                       PaginationItems, PaginatedResult, Title, Trailer};
+pub use self::genre::Genre;
+pub use self::magazine::Magazine;
+pub use self::manga::Manga;
+pub use self::person::Person;
+pub use self::producer::Producer;
+pub use self::search::SearchQuery;
+pub use self::season::ArchivedSeason;
+pub use self::user::UserProfile;
+
+mod anime;
+mod character;
+mod club;
+mod common;
+mod genre;
+mod magazine;
+mod manga;
+mod person;
+mod producer;
+mod schedule;
+mod search;
+mod season;
+mod top;
+mod user;
+
+pub const BASE_URL: &str = "https://api.jikan.moe/v4";
+
+/// Jikan v4 response envelope.
+#[derive(Deserialize, Debug)]
+struct Response<T> {
+    data: T,
+    pagination: Option<Pagination>,
+}
+
+pub(crate) async fn get<T: DeserializeOwned>(url: &str, http_clt: &HttpClient) -> Result<T> {
+    let body = request::get(url, http_clt).await?;
+    let response: Response<T> = from_json(&body)?;
+    Ok(response.data)
+}
+
+async fn get_page<T: DeserializeOwned>(url: &str, http_clt: &HttpClient) -> Result<PaginatedResult<T>> {
+    let body = request::get(url, http_clt).await?;
+    let response: Response<Vec<T>> = from_json(&body)?;
+    Ok(PaginatedResult {
+        data: response.data,
+        pagination: response.pagination.unwrap_or_default(),
+    })
+}
+
+#[derive(Default, Clone)]
+pub struct Jikan {
+    http_client: HttpClient,
+}
+
+impl Jikan {
+    /// Constructs a new v4 client targeting `https://api.jikan.moe/v4`.
+    ///
+    /// Use `client::Jikan::v4` to share the configuration of an existing v3 client instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jikan_rs::v4::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// ```
+    pub fn new() -> Jikan {
+        Jikan {
+            http_client: HttpClient::default().v4()
+        }
+    }
+
+    pub(crate) fn from_http_client(http_client: HttpClient) -> Jikan {
+        Jikan { http_client }
+    }
+
+    /// Get the anime providing its MAL id.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::v4::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// // Returns Cowboy Bebop
+    /// let anime = jikan.find_anime(1)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn find_anime(&self, mal_id: impl Into<AnimeId>) -> Result<Anime> {
+        anime::find_anime(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the manga providing its MAL id.
+    pub async fn find_manga(&self, mal_id: impl Into<MangaId>) -> Result<Manga> {
+        manga::find_manga(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the character providing its MAL id.
+    pub async fn find_character(&self, mal_id: impl Into<CharacterId>) -> Result<Character> {
+        character::find_character(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the person providing its MAL id.
+    pub async fn find_person(&self, mal_id: impl Into<PersonId>) -> Result<Person> {
+        person::find_person(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the animes of a season, `Season::Later` returns the upcoming animes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::prelude::Season;
+    /// use jikan_rs::v4::Jikan;
+    ///
+    /// let jikan = Jikan::new();
+    /// let season = jikan.find_season(Season::Spring(2020), 1)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn find_season(&self, season: Season, page: u32) -> Result<PaginatedResult<Anime>> {
+        season::find_season(season, page, &self.http_client).await
+    }
+
+    /// Get the animes airing this season.
+    pub async fn find_current_season(&self, page: u32) -> Result<PaginatedResult<Anime>> {
+        season::find_current_season(page, &self.http_client).await
+    }
+
+    /// Get every year and season available.
+    pub async fn find_season_archives(&self) -> Result<Vec<ArchivedSeason>> {
+        season::find_season_archives(&self.http_client).await
+    }
+
+    /// Get the broadcast schedule, `ScheduleOn::Week` returns every day.
+    pub async fn find_schedule(&self, schedule_on: ScheduleOn, page: u32) -> Result<PaginatedResult<Anime>> {
+        schedule::find_schedule(schedule_on, page, &self.http_client).await
+    }
+
+    pub async fn find_top_anime(&self, page: u32) -> Result<PaginatedResult<Anime>> {
+        top::find_top("anime", page, &self.http_client).await
+    }
+
+    pub async fn find_top_manga(&self, page: u32) -> Result<PaginatedResult<Manga>> {
+        top::find_top("manga", page, &self.http_client).await
+    }
+
+    pub async fn find_top_characters(&self, page: u32) -> Result<PaginatedResult<Character>> {
+        top::find_top("characters", page, &self.http_client).await
+    }
+
+    pub async fn find_top_people(&self, page: u32) -> Result<PaginatedResult<Person>> {
+        top::find_top("people", page, &self.http_client).await
+    }
+
+    /// Search animes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # async fn run() {
+    /// use jikan_rs::v4::{Jikan, SearchQuery};
+    ///
+    /// let jikan = Jikan::new();
+    /// let query = SearchQuery::new()
+    ///     .query("cowboy bebop".to_string())
+    ///     .limit(5);
+    /// let result = jikan.search_anime(&query)
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub async fn search_anime(&self, query: &SearchQuery) -> Result<PaginatedResult<Anime>> {
+        search::search("anime", query, &self.http_client).await
+    }
+
+    pub async fn search_manga(&self, query: &SearchQuery) -> Result<PaginatedResult<Manga>> {
+        search::search("manga", query, &self.http_client).await
+    }
+
+    pub async fn search_characters(&self, query: &SearchQuery) -> Result<PaginatedResult<Character>> {
+        search::search("characters", query, &self.http_client).await
+    }
+
+    pub async fn search_people(&self, query: &SearchQuery) -> Result<PaginatedResult<Person>> {
+        search::search("people", query, &self.http_client).await
+    }
+
+    /// Get the profile of a user.
+    pub async fn find_user(&self, username: &str) -> Result<UserProfile> {
+        user::find_user(username, &self.http_client).await
+    }
+
+    /// Get the club providing its MAL id.
+    pub async fn find_club(&self, mal_id: impl Into<ClubId>) -> Result<Club> {
+        club::find_club(mal_id.into(), &self.http_client).await
+    }
+
+    /// Get the producer providing its MAL id.
+    pub async fn find_producer(&self, mal_id: impl Into<ProducerId>) -> Result<Producer> {
+        producer::find_producer(mal_id.into(), &self.http_client).await
+    }
+
+    pub async fn find_producers(&self, page: u32) -> Result<PaginatedResult<Producer>> {
+        producer::find_producers(page, &self.http_client).await
+    }
+
+    pub async fn find_magazines(&self, page: u32) -> Result<PaginatedResult<Magazine>> {
+        magazine::find_magazines(page, &self.http_client).await
+    }
+
+    pub async fn find_anime_genres(&self) -> Result<Vec<Genre>> {
+        genre::find_genres("anime", &self.http_client).await
+    }
+
+    pub async fn find_manga_genres(&self) -> Result<Vec<Genre>> {
+        genre::find_genres("manga", &self.http_client).await
+    }
+}
@@ -0,0 +1,27 @@
+use crate::datetime::DateTime;
+use crate::error::Result;
+use crate::id::PersonId;
+use crate::request::HttpClient;
+use crate::v4::common::Images;
+
+pub(crate) async fn find_person(mal_id: PersonId, http_clt: &HttpClient) -> Result<Person> {
+    let url = format!("{}/people/{}", http_clt.base_url(), mal_id);
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Person {
+    pub mal_id: PersonId,
+    pub url: String,
+    pub website_url: Option<String>,
+    #[serde(default)]
+    pub images: Images,
+    pub name: String,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    #[serde(default)]
+    pub alternate_names: Vec<String>,
+    pub birthday: Option<DateTime>,
+    pub favorites: Option<u32>,
+    pub about: Option<String>,
+}
@@ -0,0 +1,28 @@
+use crate::error::Result;
+use crate::id::ProducerId;
+use crate::request::HttpClient;
+use crate::v4::common::{Images, PaginatedResult, Title};
+
+pub(crate) async fn find_producer(mal_id: ProducerId, http_clt: &HttpClient) -> Result<Producer> {
+    let url = format!("{}/producers/{}", http_clt.base_url(), mal_id);
+    super::get(&url, http_clt).await
+}
+
+pub(crate) async fn find_producers(page: u32, http_clt: &HttpClient) -> Result<PaginatedResult<Producer>> {
+    let url = format!("{}/producers?page={}", http_clt.base_url(), page);
+    super::get_page(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Producer {
+    pub mal_id: ProducerId,
+    pub url: String,
+    #[serde(default)]
+    pub titles: Vec<Title>,
+    #[serde(default)]
+    pub images: Images,
+    pub favorites: Option<u32>,
+    pub count: Option<u32>,
+    pub established: Option<String>,
+    pub about: Option<String>,
+}
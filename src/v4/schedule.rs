@@ -0,0 +1,14 @@
+use crate::base::Resource;
+use crate::error::Result;
+use crate::request::HttpClient;
+use crate::schedule::ScheduleOn;
+use crate::v4::anime::Anime;
+use crate::v4::common::PaginatedResult;
+
+pub(crate) async fn find_schedule(schedule_on: ScheduleOn, page: u32, http_clt: &HttpClient) -> Result<PaginatedResult<Anime>> {
+    let url = match schedule_on {
+        ScheduleOn::Week => format!("{}/schedules?page={}", http_clt.base_url(), page),
+        schedule_on => format!("{}/schedules?filter={}&page={}", http_clt.base_url(), schedule_on.uri(), page),
+    };
+    super::get_page(&url, http_clt).await
+}
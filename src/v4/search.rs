@@ -0,0 +1,53 @@
+use percent_encoding::utf8_percent_encode;
+use serde::de::DeserializeOwned;
+
+use crate::base::FRAGMENT;
+use crate::error::Result;
+use crate::request::HttpClient;
+use crate::v4::common::PaginatedResult;
+
+pub(crate) async fn search<T: DeserializeOwned>(source: &str, query: &SearchQuery, http_clt: &HttpClient) -> Result<PaginatedResult<T>> {
+    let url = format!("{}/{}?{}", http_clt.base_url(), source, query.build());
+    super::get_page(&url, http_clt).await
+}
+
+// Query of the v4 search endpoints, values are passed to Jikan untouched
+builder!(
+    pub struct SearchQuery {
+        query: String,
+        page: u32,
+        limit: u32,
+        source_type: String,
+        status: String,
+        rating: String,
+        order_by: String,
+        sort: String,
+        genres: String,
+        letter: String,
+    }
+);
+
+impl SearchQuery {
+    fn build(&self) -> String {
+        let params = [
+            ("q", &self.query),
+            ("type", &self.source_type),
+            ("status", &self.status),
+            ("rating", &self.rating),
+            ("order_by", &self.order_by),
+            ("sort", &self.sort),
+            ("genres", &self.genres),
+            ("letter", &self.letter),
+        ];
+        let mut query = format!("page={}", self.page.unwrap_or(1));
+        if let Some(limit) = self.limit {
+            query = format!("{}&limit={}", query, limit);
+        }
+        for (name, value) in params.iter() {
+            if let Some(value) = value {
+                query = format!("{}&{}={}", query, name, utf8_percent_encode(value, FRAGMENT));
+            }
+        }
+        query
+    }
+}
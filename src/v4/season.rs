@@ -0,0 +1,31 @@
+use crate::base::Resource;
+use crate::error::Result;
+use crate::request::HttpClient;
+use crate::season::Season;
+use crate::v4::anime::Anime;
+use crate::v4::common::PaginatedResult;
+
+pub(crate) async fn find_season(season: Season, page: u32, http_clt: &HttpClient) -> Result<PaginatedResult<Anime>> {
+    let uri = match season {
+        Season::Later => "upcoming".to_string(),
+        season => season.uri(),
+    };
+    let url = format!("{}/seasons/{}?page={}", http_clt.base_url(), uri, page);
+    super::get_page(&url, http_clt).await
+}
+
+pub(crate) async fn find_current_season(page: u32, http_clt: &HttpClient) -> Result<PaginatedResult<Anime>> {
+    let url = format!("{}/seasons/now?page={}", http_clt.base_url(), page);
+    super::get_page(&url, http_clt).await
+}
+
+pub(crate) async fn find_season_archives(http_clt: &HttpClient) -> Result<Vec<ArchivedSeason>> {
+    let url = format!("{}/seasons", http_clt.base_url());
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArchivedSeason {
+    pub year: u16,
+    pub seasons: Vec<String>,
+}
@@ -0,0 +1,10 @@
+use serde::de::DeserializeOwned;
+
+use crate::error::Result;
+use crate::request::HttpClient;
+use crate::v4::common::PaginatedResult;
+
+pub(crate) async fn find_top<T: DeserializeOwned>(source: &str, page: u32, http_clt: &HttpClient) -> Result<PaginatedResult<T>> {
+    let url = format!("{}/top/{}?page={}", http_clt.base_url(), source, page);
+    super::get_page(&url, http_clt).await
+}
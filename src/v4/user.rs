@@ -0,0 +1,23 @@
+use crate::datetime::DateTime;
+use crate::error::Result;
+use crate::request::HttpClient;
+use crate::v4::common::Images;
+
+pub(crate) async fn find_user(username: &str, http_clt: &HttpClient) -> Result<UserProfile> {
+    let url = format!("{}/users/{}", http_clt.base_url(), username);
+    super::get(&url, http_clt).await
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UserProfile {
+    pub mal_id: Option<u32>,
+    pub username: String,
+    pub url: String,
+    #[serde(default)]
+    pub images: Images,
+    pub last_online: Option<DateTime>,
+    pub gender: Option<String>,
+    pub birthday: Option<DateTime>,
+    pub location: Option<String>,
+    pub joined: Option<DateTime>,
+}
@@ -0,0 +1,143 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::base::{Date, EntityRef, RelationKind};
+use crate::datetime::DateTime;
+use crate::error::Result;
+use crate::franchise::{self, WalkEntry};
+use crate::id::AnimeId;
+use crate::request::HttpClient;
+use crate::search::enums::AnimeType;
+
+const DEFAULT_MAX_ENTRIES: usize = 100;
+
+const WATCH_RELATIONS: [RelationKind; 6] = [
+    RelationKind::Prequel,
+    RelationKind::Sequel,
+    RelationKind::SideStory,
+    RelationKind::ParentStory,
+    RelationKind::Summary,
+    RelationKind::FullStory,
+];
+
+builder!(
+    pub struct WatchOrderQuery {
+        pub(crate) max_entries: usize,
+        pub(crate) exclude_types: Vec<AnimeType>,
+        pub(crate) exclude_summaries: bool,
+    }
+);
+
+pub(crate) async fn find_watch_order(mal_id: AnimeId, seed_entry: Option<WalkEntry>, query: WatchOrderQuery,
+                                     http_clt: &HttpClient) -> Result<WatchOrder> {
+    let max_entries = query.max_entries.unwrap_or(DEFAULT_MAX_ENTRIES);
+    let follow = |kind: &RelationKind, entity| matches!(entity, EntityRef::Anime(_)) && WATCH_RELATIONS.contains(kind);
+    let walk = franchise::walk(EntityRef::Anime(mal_id), seed_entry, max_entries, follow, http_clt).await?;
+
+    // A summary is the target of a Summary relation, or the source of a Full story one
+    let summaries: HashSet<EntityRef> = walk.edges.iter()
+        .filter_map(|edge| match edge.relation {
+            RelationKind::Summary => Some(edge.to),
+            RelationKind::FullStory => Some(edge.from),
+            _ => None,
+        })
+        .collect();
+    let entries: Vec<WatchOrderEntry> = walk.entries.into_iter()
+        .filter_map(|(entity, entry)| match entry {
+            WalkEntry::Anime(anime) => Some(WatchOrderEntry {
+                mal_id: AnimeId(entity.mal_id()),
+                title: anime.title,
+                anime_type: anime.anime_type,
+                episodes: anime.episodes,
+                aired_from: anime.aired.from,
+                summary: summaries.contains(&entity),
+            }),
+            WalkEntry::Manga(_) => None,
+        })
+        .collect();
+
+    // Each pair reads "the first entry takes place before the second one"
+    let constraints: Vec<(AnimeId, AnimeId)> = walk.edges.iter()
+        .filter_map(|edge| {
+            let (from, to) = (AnimeId(edge.from.mal_id()), AnimeId(edge.to.mal_id()));
+            match edge.relation {
+                RelationKind::Sequel | RelationKind::SideStory | RelationKind::Summary => Some((from, to)),
+                RelationKind::Prequel | RelationKind::ParentStory | RelationKind::FullStory => Some((to, from)),
+                _ => None,
+            }
+        })
+        .collect();
+
+    let mut release = entries.clone();
+    release.sort_by_key(WatchOrderEntry::release_key);
+    let chronological = chronological_order(entries, &constraints);
+
+    let exclude_types = query.exclude_types.unwrap_or_default();
+    let exclude_summaries = query.exclude_summaries.unwrap_or(false);
+    let excluded = |entry: &WatchOrderEntry| exclude_types.contains(&entry.anime_type) || (exclude_summaries && entry.summary);
+    Ok(WatchOrder {
+        chronological: chronological.into_iter().filter(|entry| !excluded(entry)).collect(),
+        release: release.into_iter().filter(|entry| !excluded(entry)).collect(),
+        truncated: walk.truncated,
+    })
+}
+
+/// Sorts the entries so each one comes after the entries taking place before it, earlier
+/// releases first. Contradicting relations are broken at the earliest release.
+fn chronological_order(entries: Vec<WatchOrderEntry>, constraints: &[(AnimeId, AnimeId)]) -> Vec<WatchOrderEntry> {
+    let mut pending: HashMap<AnimeId, WatchOrderEntry> = entries.into_iter()
+        .map(|entry| (entry.mal_id, entry))
+        .collect();
+    let mut preceding: HashMap<AnimeId, HashSet<AnimeId>> = HashMap::new();
+    for (before, after) in constraints {
+        if before != after && pending.contains_key(before) && pending.contains_key(after) {
+            preceding.entry(*after).or_default().insert(*before);
+        }
+    }
+
+    let mut order = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let ready: BTreeSet<_> = pending.values()
+            .filter(|entry| !preceding.contains_key(&entry.mal_id))
+            .map(WatchOrderEntry::release_key)
+            .collect();
+        let next = match ready.into_iter().next() {
+            Some((_, _, mal_id)) => mal_id,
+            None => pending.values().map(WatchOrderEntry::release_key).min().map(|(_, _, mal_id)| mal_id).unwrap(),
+        };
+        preceding.retain(|_, before| {
+            before.remove(&next);
+            !before.is_empty()
+        });
+        order.push(pending.remove(&next).unwrap());
+    }
+    order
+}
+
+/// Entries of an anime series, in story and release order.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct WatchOrder {
+    /// Entries ordered by their relations, following prequels and sequels.
+    pub chronological: Vec<WatchOrderEntry>,
+    /// Entries ordered by the start of their airing, unaired entries last.
+    pub release: Vec<WatchOrderEntry>,
+    /// Whether entries were left out because the walk reached `max_entries`.
+    pub truncated: bool,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WatchOrderEntry {
+    pub mal_id: AnimeId,
+    pub title: String,
+    pub anime_type: AnimeType,
+    pub episodes: Option<u16>,
+    pub aired_from: Option<DateTime>,
+    /// Whether the entry recaps another one.
+    pub summary: bool,
+}
+
+impl WatchOrderEntry {
+    fn release_key(&self) -> (bool, Option<Date>, AnimeId) {
+        let aired_from = self.aired_from.as_ref().and_then(DateTime::date);
+        (aired_from.is_none(), aired_from, self.mal_id)
+    }
+}
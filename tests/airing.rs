@@ -0,0 +1,69 @@
+extern crate jikan_rs;
+
+mod common;
+
+use std::time::Duration;
+
+use jikan_rs::airing::{parse_broadcast, parse_duration, parse_premiered};
+use jikan_rs::prelude::*;
+
+#[test]
+fn should_parse_broadcasts() {
+    let broadcast = parse_broadcast("Saturdays at 01:00 (JST)").unwrap();
+    assert_eq!(broadcast.weekday, Weekday::Saturday);
+    assert_eq!((broadcast.hour, broadcast.minute), (1, 0));
+    assert_eq!(broadcast.timezone, "JST");
+    assert_eq!(broadcast.utc_offset(), Some(9 * 3600));
+    assert_eq!(broadcast.seconds_from_monday(), 5 * 86400 + 3600);
+
+    let broadcast = parse_broadcast("Sundays at 23:30 (UTC)").unwrap();
+    assert_eq!(broadcast.weekday, Weekday::Sunday);
+    assert_eq!(broadcast.utc_offset(), Some(0));
+
+    assert!(parse_broadcast("Unknown").is_none());
+    assert!(parse_broadcast("Saturdays at Unknown").is_none());
+    assert!(parse_broadcast("Saturdays at 24:00 (JST)").is_none());
+    assert!(parse_broadcast("Someday at 01:00 (JST)").is_none());
+}
+
+#[test]
+fn should_parse_durations() {
+    assert_eq!(parse_duration("24 min per ep"), Some(Duration::from_secs(24 * 60)));
+    assert_eq!(parse_duration("1 hr 55 min"), Some(Duration::from_secs(115 * 60)));
+    assert_eq!(parse_duration("30 sec per ep"), Some(Duration::from_secs(30)));
+    assert_eq!(parse_duration("2 hrs."), Some(Duration::from_secs(2 * 3600)));
+
+    assert!(parse_duration("Unknown").is_none());
+    assert!(parse_duration("24 eps").is_none());
+    assert!(parse_duration("99999999999999999 hr").is_none());
+    assert!(parse_duration("18446744073709551615 sec 1 sec").is_none());
+}
+
+#[test]
+fn should_parse_premiered_seasons() {
+    assert_eq!(parse_premiered("Spring 1998"), Some(Season::Spring(1998)));
+    assert_eq!(parse_premiered("Fall 2001"), Some(Season::Fall(2001)));
+
+    assert!(parse_premiered("?").is_none());
+    assert!(parse_premiered("Spring ?").is_none());
+    assert!(parse_premiered("Monsoon 2001").is_none());
+}
+
+#[tokio::test]
+async fn should_parse_airing_details_of_an_anime() {
+    let (jikan, _) = common::jikan(&[("/anime/1", include_str!("fixtures/anime.json"))]);
+    let anime = jikan.find_anime(1).await.unwrap();
+    assert_eq!(anime.broadcast_time().unwrap().weekday, Weekday::Saturday);
+    assert_eq!(anime.episode_duration(), Some(Duration::from_secs(24 * 60)));
+    assert_eq!(anime.total_duration(), Some(Duration::from_secs(26 * 24 * 60)));
+    assert_eq!(anime.premiered_season(), Some(Season::Spring(1998)));
+}
+
+#[tokio::test]
+async fn should_not_overflow_the_total_duration() {
+    let body = include_str!("fixtures/anime.json").replace("\"24 min per ep\"", "\"4000000000000000 hr\"");
+    let (jikan, _) = common::jikan(&[("/anime/1", &body)]);
+    let anime = jikan.find_anime(1).await.unwrap();
+    assert!(anime.episode_duration().is_some());
+    assert!(anime.total_duration().is_none());
+}
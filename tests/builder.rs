@@ -0,0 +1,85 @@
+extern crate jikan_rs;
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc;
+use std::thread;
+
+use jikan_rs::client::Jikan;
+use jikan_rs::Error;
+use jikan_rs::prelude::{AnimeId, SourceType};
+
+/// Serves the given responses, one per connection, and reports the request lines received.
+fn serve(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}/v3", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            let request = String::from_utf8(request).unwrap();
+            sender.send(request.lines().next().unwrap().to_string()).unwrap();
+            let response = format!(
+                "HTTP/1.1 {} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status, body.len(), body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+    });
+    (base_url, receiver)
+}
+
+#[tokio::test]
+async fn should_request_the_configured_base_url() {
+    let body = r#"{"request_hash":"h","request_cached":false,"request_cache_expiry":0,"moreinfo":"Suggested Order of Viewing"}"#;
+    let (base_url, requests) = serve(vec![(200, body)]);
+    let jikan = Jikan::builder()
+        .base_url(base_url)
+        .build()
+        .unwrap();
+
+    let more_info = jikan.find_more_info(SourceType::Anime(AnimeId(1))).await.unwrap();
+
+    assert_eq!(more_info.unwrap(), "Suggested Order of Viewing");
+    assert_eq!(requests.recv().unwrap(), "GET /v3/anime/1/moreinfo HTTP/1.1");
+}
+
+#[tokio::test]
+async fn should_return_not_found_with_jikan_error() {
+    let body = r#"{"status":404,"type":"BadResponseException","message":"Resource does not exist","error":"404 on https://myanimelist.net/anime/2/"}"#;
+    let (base_url, _requests) = serve(vec![(404, body)]);
+    let jikan = Jikan::builder()
+        .base_url(base_url)
+        .build()
+        .unwrap();
+
+    let error = jikan.find_anime(2).await.unwrap_err();
+
+    assert!(error.is_not_found());
+    assert_eq!(error.api_error().unwrap().message, "Resource does not exist");
+}
+
+#[tokio::test]
+async fn should_return_upstream_error_for_server_failures() {
+    let (base_url, _requests) = serve(vec![(503, "<html>Service Unavailable</html>")]);
+    let jikan = Jikan::builder()
+        .base_url(base_url)
+        .build()
+        .unwrap();
+
+    let error = jikan.find_manga(1).await.unwrap_err();
+
+    match error {
+        Error::Upstream { status, error } => {
+            assert_eq!(status, 503);
+            assert!(error.is_none());
+        }
+        error => panic!("Unexpected error: {}", error),
+    }
+}
@@ -0,0 +1,267 @@
+extern crate jikan_rs;
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use jikan_rs::cache::{Cache, CacheEntry, CacheStats, DiskCache, MemoryCache};
+use jikan_rs::client::Jikan;
+use jikan_rs::transport::{FixtureTransport, Response};
+
+const ANIME: &str = include_str!("fixtures/anime.json");
+
+fn jikan(cache: Arc<MemoryCache>) -> (Jikan, Arc<FixtureTransport>) {
+    let fixtures = Arc::new(FixtureTransport::new());
+    let jikan = Jikan::builder()
+        .transport(fixtures.clone())
+        .cache(cache)
+        .build()
+        .unwrap();
+    (jikan, fixtures)
+}
+
+fn entry(body: &str) -> CacheEntry {
+    CacheEntry {
+        body: body.to_string(),
+        request_hash: None,
+        expires_at: SystemTime::now() + Duration::from_secs(60),
+    }
+}
+
+#[tokio::test]
+async fn should_serve_repeated_requests_from_cache() {
+    let cache = Arc::new(MemoryCache::new(10));
+    let (jikan, fixtures) = jikan(cache.clone());
+    fixtures.route("/anime/1", Response::new(200, ANIME));
+
+    let first = jikan.find_anime(1).await.unwrap();
+    let second = jikan.find_anime(1).await.unwrap();
+
+    assert_eq!(first.title, second.title);
+    assert!(!first.meta().from_local_cache);
+    assert!(second.meta().from_local_cache);
+    assert_eq!(second.meta().request_hash, first.meta().request_hash);
+    assert_eq!(fixtures.requests().len(), 1);
+    assert_eq!(cache.len(), 1);
+}
+
+#[tokio::test]
+async fn should_skip_lookup_when_bypassing_cache() {
+    let (jikan, fixtures) = jikan(Arc::new(MemoryCache::new(10)));
+    fixtures.route("/anime/1", Response::new(200, ANIME));
+
+    jikan.find_anime(1).await.unwrap();
+    jikan.bypass_cache().find_anime(1).await.unwrap();
+    jikan.find_anime(1).await.unwrap();
+
+    assert_eq!(fixtures.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_fetch_again_after_invalidation() {
+    let (jikan, fixtures) = jikan(Arc::new(MemoryCache::new(10)));
+    fixtures.route("/anime/1", Response::new(200, ANIME));
+
+    jikan.find_anime(1).await.unwrap();
+    jikan.invalidate_cache("/anime/1");
+    jikan.find_anime(1).await.unwrap();
+    jikan.clear_cache();
+    jikan.find_anime(1).await.unwrap();
+
+    assert_eq!(fixtures.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn should_fetch_again_once_expired() {
+    let (jikan, fixtures) = jikan(Arc::new(MemoryCache::new(10)));
+    let expired = ANIME.replace("\"request_cache_expiry\": 43200", "\"request_cache_expiry\": 0");
+    fixtures.route("/anime/1", Response::new(200, &expired));
+
+    jikan.find_anime(1).await.unwrap();
+    jikan.find_anime(1).await.unwrap();
+
+    assert_eq!(fixtures.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_not_cache_responses_without_expiry() {
+    let cache = Arc::new(MemoryCache::new(10));
+    let (jikan, fixtures) = jikan(cache.clone());
+    fixtures.route("/meta/status", Response::new(200, include_str!("fixtures/meta_status.json")));
+
+    jikan.retrieve_api_status().await.unwrap();
+    jikan.retrieve_api_status().await.unwrap();
+
+    assert_eq!(fixtures.requests().len(), 2);
+    assert!(cache.is_empty());
+}
+
+#[tokio::test]
+async fn should_not_cache_errors() {
+    let cache = Arc::new(MemoryCache::new(10));
+    let (jikan, _) = jikan(cache.clone());
+
+    assert!(jikan.find_anime(1).await.is_err());
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn should_evict_least_recently_used_entry() {
+    let cache = MemoryCache::new(2);
+    cache.put("a", entry("a"));
+    cache.put("b", entry("b"));
+    cache.get("a");
+    cache.put("c", entry("c"));
+
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("a").is_some());
+    assert!(cache.get("b").is_none());
+    assert!(cache.get("c").is_some());
+}
+
+#[test]
+fn should_track_recency_across_replacements_and_removals() {
+    let cache = MemoryCache::new(2);
+    cache.put("a", entry("a"));
+    cache.put("b", entry("b"));
+    cache.put("a", entry("a2"));
+    cache.put("c", entry("c"));
+    assert!(cache.get("b").is_none());
+    assert_eq!(cache.get("a").unwrap().body, "a2");
+
+    cache.remove("a");
+    cache.put("d", entry("d"));
+    cache.put("e", entry("e"));
+    assert_eq!(cache.len(), 2);
+    assert!(cache.get("c").is_none());
+    assert!(cache.get("d").is_some());
+    assert!(cache.get("e").is_some());
+}
+
+#[test]
+fn should_read_expiry_and_hash_from_body() {
+    let entry = CacheEntry::from_body(ANIME).unwrap();
+    assert!(!entry.is_expired());
+    assert_eq!(entry.request_hash.as_deref(), Some("request:anime:7ee3a3f2cda3f1ac8e0bd8ef7a5a2dc1dd1bb7e6"));
+    assert!(CacheEntry::from_body("{}").is_none());
+}
+
+fn disk_cache_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("jikan-rs-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[tokio::test]
+async fn should_keep_disk_entries_across_instances() {
+    let dir = disk_cache_dir("persist");
+    let fixtures = Arc::new(FixtureTransport::new());
+    fixtures.route("/anime/1", Response::new(200, ANIME));
+
+    for _ in 0..2 {
+        let jikan = Jikan::builder()
+            .transport(fixtures.clone())
+            .cache(Arc::new(DiskCache::open(&dir).unwrap()))
+            .build()
+            .unwrap();
+        assert_eq!(jikan.find_anime(1).await.unwrap().title, "Cowboy Bebop");
+    }
+
+    assert_eq!(fixtures.requests().len(), 1);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_report_disk_cache_hit_rate() {
+    let dir = disk_cache_dir("stats");
+    let cache = DiskCache::open(&dir).unwrap();
+    cache.put("http://jikan.test/v3/anime/1", entry(ANIME));
+
+    assert_eq!(cache.get("http://jikan.test/v3/anime/1").unwrap().body, ANIME);
+    assert!(cache.get("http://jikan.test/v3/anime/2").is_none());
+    assert!(cache.get("http://jikan.test/v3/anime/2").is_none());
+
+    let stats = cache.stats();
+    assert_eq!(stats, CacheStats { hits: 1, misses: 2, entries: 1 });
+    assert!((stats.hit_rate() - 1.0 / 3.0).abs() < f64::EPSILON);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_prune_expired_disk_entries() {
+    let dir = disk_cache_dir("prune");
+    let cache = DiskCache::open(&dir).unwrap();
+    let mut expired = entry("expired");
+    expired.expires_at = SystemTime::now() - Duration::from_secs(1);
+    cache.put("expired", expired);
+    cache.put("fresh", entry("fresh"));
+    fs::write(dir.join("corrupted.json.gz"), b"not gzip").unwrap();
+
+    assert_eq!(cache.prune_expired().unwrap(), 2);
+    assert_eq!(cache.stats().entries, 1);
+    assert!(cache.get("fresh").is_some());
+
+    cache.clear();
+    assert_eq!(cache.stats().entries, 0);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_survive_concurrent_writes_of_one_key() {
+    let dir = disk_cache_dir("concurrent");
+    let cache = Arc::new(DiskCache::open(&dir).unwrap());
+    let body = ANIME.repeat(20);
+    // Half of the writers share a cache, the others open their own on the same directory
+    let writers: Vec<_> = (0..8)
+        .map(|writer| {
+            let cache = if writer % 2 == 0 { cache.clone() } else { Arc::new(DiskCache::open(&dir).unwrap()) };
+            let body = body.clone();
+            thread::spawn(move || {
+                for _ in 0..10 {
+                    cache.put("anime", entry(&body));
+                }
+            })
+        })
+        .collect();
+    for writer in writers {
+        writer.join().unwrap();
+    }
+
+    assert_eq!(cache.get("anime").unwrap().body, body);
+    assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn should_prune_while_entries_are_cleared() {
+    let dir = disk_cache_dir("prune_race");
+    let cache = Arc::new(DiskCache::open(&dir).unwrap());
+    for round in 0..20 {
+        for key in 0..20 {
+            let mut expired = entry(ANIME);
+            expired.expires_at = SystemTime::now() - Duration::from_secs(1);
+            cache.put(&format!("anime/{}", key), expired);
+        }
+        let clearing = {
+            let cache = cache.clone();
+            thread::spawn(move || cache.clear())
+        };
+        assert!(cache.prune_expired().is_ok(), "round {}", round);
+        clearing.join().unwrap();
+    }
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn should_report_memory_cache_stats_through_client() {
+    let (jikan, fixtures) = jikan(Arc::new(MemoryCache::new(10)));
+    fixtures.route("/anime/1", Response::new(200, ANIME));
+
+    jikan.find_anime(1).await.unwrap();
+    jikan.find_anime(1).await.unwrap();
+
+    assert_eq!(jikan.cache_stats(), Some(CacheStats { hits: 1, misses: 1, entries: 1 }));
+    assert_eq!(Jikan::new().cache_stats(), None);
+}
@@ -1,118 +1,98 @@
-extern crate jikan_rs;
+#![allow(clippy::len_zero, clippy::assertions_on_constants)]
 
-use std::thread;
-use std::time::Duration;
+extern crate jikan_rs;
+#[macro_use]
+extern crate lazy_static;
 
 use jikan_rs::client::Jikan;
 use jikan_rs::prelude::*;
+use jikan_rs::RateLimiter;
+
+lazy_static! {
+    static ref JIKAN: Jikan = Jikan::new().with_rate_limiter(RateLimiter::default());
+}
 
 #[tokio::test]
 async fn should_find_an_anime() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let anime = jikan.find_anime(1).await.unwrap();
+    let anime = JIKAN.find_anime(1).await.unwrap();
     assert_eq!(anime.mal_id, 1);
     assert_eq!(anime.title, "Cowboy Bebop");
 }
 
 #[tokio::test]
 async fn should_find_a_manga() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let manga = jikan.find_manga(1).await.unwrap();
+    let manga = JIKAN.find_manga(1).await.unwrap();
     assert_eq!(manga.mal_id, 1);
     assert_eq!(manga.title, "Monster");
 }
 
 #[tokio::test]
 async fn should_find_a_person() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let person = jikan.find_person(1).await.unwrap();
+    let person = JIKAN.find_person(1).await.unwrap();
     assert_eq!(person.mal_id, 1);
     assert_eq!(person.name, "Tomokazu Seki");
 }
 
 #[tokio::test]
 async fn should_find_a_character() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let character = jikan.find_character(1).await.unwrap();
+    let character = JIKAN.find_character(1).await.unwrap();
     assert_eq!(character.mal_id, 1);
     assert_eq!(character.name, "Spike Spiegel");
 }
 
 #[tokio::test]
 async fn should_find_anime_characters() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let characters_staff = jikan.find_anime_characters(1).await.unwrap();
+    let characters_staff = JIKAN.find_anime_characters(1).await.unwrap();
     assert!(characters_staff.characters.len() > 0);
     assert!(characters_staff.staff.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_manga_characters() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let characters = jikan.find_manga_characters(1).await.unwrap();
+    let characters = JIKAN.find_manga_characters(1).await.unwrap();
     assert!(characters.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_anime_episodes_info() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let episodes = jikan.find_episodes(1).await.unwrap();
+    let episodes = JIKAN.find_episodes(1).await.unwrap();
     assert_eq!(episodes.len(), 26);
 }
 
 #[tokio::test]
 async fn should_find_anime_news() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let news = jikan.find_news(SourceType::Anime(1)).await.unwrap();
+    let news = JIKAN.find_news(SourceType::Anime(AnimeId(1))).await.unwrap();
     assert!(news.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_manga_news() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let news = jikan.find_news(SourceType::Manga(1)).await.unwrap();
+    let news = JIKAN.find_news(SourceType::Manga(MangaId(1))).await.unwrap();
     assert!(news.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_anime_pictures() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let pictures = jikan.find_pictures(SourceType::Anime(1)).await.unwrap();
+    let pictures = JIKAN.find_pictures(SourceType::Anime(AnimeId(1))).await.unwrap();
     assert!(pictures.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_manga_pictures() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let pictures = jikan.find_pictures(SourceType::Manga(1)).await.unwrap();
+    let pictures = JIKAN.find_pictures(SourceType::Manga(MangaId(1))).await.unwrap();
     assert!(pictures.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_anime_videos() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let videos = jikan.find_videos(1).await.unwrap();
+    let videos = JIKAN.find_videos(1).await.unwrap();
     assert!(videos.promo.len() > 0);
     assert!(videos.episodes.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_anime_stats() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let stats = jikan.find_stats(SourceType::Anime(1)).await.unwrap();
+    let stats = JIKAN.find_stats(SourceType::Anime(AnimeId(1))).await.unwrap();
     let stats = match stats {
         Stats::Anime(stats) => Some(stats),
         _ => None
@@ -122,9 +102,7 @@ async fn should_find_anime_stats() {
 
 #[tokio::test]
 async fn should_find_manga_stats() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let stats = jikan.find_stats(SourceType::Manga(1)).await.unwrap();
+    let stats = JIKAN.find_stats(SourceType::Manga(MangaId(1))).await.unwrap();
     let stats = match stats {
         Stats::Manga(stats) => Some(stats),
         _ => None
@@ -134,41 +112,31 @@ async fn should_find_manga_stats() {
 
 #[tokio::test]
 async fn should_find_anime_forum() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let topics = jikan.find_forum(SourceType::Anime(1)).await.unwrap();
+    let topics = JIKAN.find_forum(SourceType::Anime(AnimeId(1))).await.unwrap();
     assert!(topics.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_manga_forum() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let topics = jikan.find_forum(SourceType::Manga(1)).await.unwrap();
+    let topics = JIKAN.find_forum(SourceType::Manga(MangaId(1))).await.unwrap();
     assert!(topics.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_more_anime_info() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let more_info = jikan.find_more_info(SourceType::Anime(1)).await.unwrap().unwrap();
+    let more_info = JIKAN.find_more_info(SourceType::Anime(AnimeId(1))).await.unwrap().unwrap();
     assert!(!more_info.is_empty());
 }
 
 #[tokio::test]
 async fn should_find_more_manga_info() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let more_info = jikan.find_more_info(SourceType::Manga(2)).await.unwrap().unwrap();
+    let more_info = JIKAN.find_more_info(SourceType::Manga(MangaId(2))).await.unwrap().unwrap();
     assert!(!more_info.is_empty());
 }
 
 #[tokio::test]
 async fn should_find_anime_reviews() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let reviews = jikan.find_reviews(SourceType::Anime(1), 1).await.unwrap();
+    let reviews = JIKAN.find_reviews(SourceType::Anime(AnimeId(1)), 1).await.unwrap();
     let reviews = match reviews {
         Reviews::Anime(u) => Some(u),
         _ => None,
@@ -178,9 +146,7 @@ async fn should_find_anime_reviews() {
 
 #[tokio::test]
 async fn should_find_manga_reviews() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let reviews = jikan.find_reviews(SourceType::Manga(1), 1).await.unwrap();
+    let reviews = JIKAN.find_reviews(SourceType::Manga(MangaId(1)), 1).await.unwrap();
     let reviews = match reviews {
         Reviews::Manga(u) => Some(u),
         _ => None,
@@ -190,25 +156,19 @@ async fn should_find_manga_reviews() {
 
 #[tokio::test]
 async fn should_find_anime_recommendations() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let recommendations = jikan.find_recommendations(SourceType::Anime(1)).await.unwrap();
+    let recommendations = JIKAN.find_recommendations(SourceType::Anime(AnimeId(1))).await.unwrap();
     assert!(recommendations.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_manga_recommendations() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let recommendations = jikan.find_recommendations(SourceType::Manga(1)).await.unwrap();
+    let recommendations = JIKAN.find_recommendations(SourceType::Manga(MangaId(1))).await.unwrap();
     assert!(recommendations.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_anime_user_updates() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let user_updates = jikan.find_user_updates(SourceType::Anime(1), 1).await.unwrap();
+    let user_updates = JIKAN.find_user_updates(SourceType::Anime(AnimeId(1)), 1).await.unwrap();
     let user_updates = match user_updates {
         UserUpdates::Anime(u) => Some(u),
         _ => None,
@@ -218,9 +178,7 @@ async fn should_find_anime_user_updates() {
 
 #[tokio::test]
 async fn should_find_manga_user_updates() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let user_updates = jikan.find_user_updates(SourceType::Manga(1), 1).await.unwrap();
+    let user_updates = JIKAN.find_user_updates(SourceType::Manga(MangaId(1)), 1).await.unwrap();
     let user_updates = match user_updates {
         UserUpdates::Manga(u) => Some(u),
         _ => None,
@@ -230,34 +188,26 @@ async fn should_find_manga_user_updates() {
 
 #[tokio::test]
 async fn should_find_a_season() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let season = jikan.find_season(Season::Winter(2020)).await.unwrap();
+    let season = JIKAN.find_season(Season::Winter(2020)).await.unwrap();
     assert!(season.animes.len() > 0);
 }
 
 
 #[tokio::test]
 async fn should_find_animes_with_undefined_season() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let season = jikan.find_season(Season::Later).await.unwrap();
+    let season = JIKAN.find_season(Season::Later).await.unwrap();
     assert!(season.animes.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_season_archives() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let archives = jikan.find_season_archives().await.unwrap();
+    let archives = JIKAN.find_season_archives().await.unwrap();
     assert!(archives.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_schedule() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let schedule = jikan.find_schedule(ScheduleOn::Week).await.unwrap();
+    let schedule = JIKAN.find_schedule(ScheduleOn::Week).await.unwrap();
     assert!(schedule.monday.len() > 0);
     assert!(schedule.tuesday.len() > 0);
     assert!(schedule.wednesday.len() > 0);
@@ -271,9 +221,7 @@ async fn should_find_schedule() {
 
 #[tokio::test]
 async fn should_find_schedule_for_specific_day() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let schedule = jikan.find_schedule(ScheduleOn::Sunday).await.unwrap();
+    let schedule = JIKAN.find_schedule(ScheduleOn::Sunday).await.unwrap();
     assert!(schedule.sunday.len() > 0);
     assert!(schedule.monday.is_empty());
     assert!(schedule.tuesday.is_empty());
@@ -287,9 +235,7 @@ async fn should_find_schedule_for_specific_day() {
 
 #[tokio::test]
 async fn should_find_top_anime() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let top_anime = jikan.find_top(Top::Anime { page: 1, subtype: TopAnimeSubtype::All }).await.unwrap();
+    let top_anime = JIKAN.find_top(Top::Anime { page: 1, subtype: TopAnimeSubtype::All }).await.unwrap();
     let top_anime = match top_anime {
         TopResult::Anime(top_anime) => Some(top_anime),
         _ => None
@@ -299,9 +245,7 @@ async fn should_find_top_anime() {
 
 #[tokio::test]
 async fn should_find_top_manga() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let top_manga = jikan.find_top(Top::Manga { page: 1, subtype: TopMangaSubtype::All }).await.unwrap();
+    let top_manga = JIKAN.find_top(Top::Manga { page: 1, subtype: TopMangaSubtype::All }).await.unwrap();
     let top_manga = match top_manga {
         TopResult::Manga(top_manga) => Some(top_manga),
         _ => None
@@ -311,9 +255,7 @@ async fn should_find_top_manga() {
 
 #[tokio::test]
 async fn should_find_top_characters() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let top_character = jikan.find_top(Top::Character(1)).await.unwrap();
+    let top_character = JIKAN.find_top(Top::Character(1)).await.unwrap();
     let top_character = match top_character {
         TopResult::Character(top_character) => Some(top_character),
         _ => None
@@ -323,9 +265,7 @@ async fn should_find_top_characters() {
 
 #[tokio::test]
 async fn should_find_top_people() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let top_people = jikan.find_top(Top::People(1)).await.unwrap();
+    let top_people = JIKAN.find_top(Top::People(1)).await.unwrap();
     let top_people = match top_people {
         TopResult::People(top_people) => Some(top_people),
         _ => None
@@ -335,43 +275,33 @@ async fn should_find_top_people() {
 
 #[tokio::test]
 async fn should_find_action_animes() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let action_animes = jikan.find_animes_with_genre(AnimeGenre::Action, 1).await.unwrap();
+    let action_animes = JIKAN.find_animes_with_genre(AnimeGenre::Action, 1).await.unwrap();
     assert_eq!(action_animes.mal_url.name, "Action Anime");
     assert!(action_animes.animes.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_shoujo_mangas() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let shoujo_mangas = jikan.find_mangas_with_genre(MangaGenre::Shoujo, 1).await.unwrap();
+    let shoujo_mangas = JIKAN.find_mangas_with_genre(MangaGenre::Shoujo, 1).await.unwrap();
     assert_eq!(shoujo_mangas.mal_url.name, "Shoujo Manga");
     assert!(shoujo_mangas.mangas.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_a_producer() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let producer = jikan.find_producer(1, 1).await.unwrap();
+    let producer = JIKAN.find_producer(1, 1).await.unwrap();
     assert!(producer.animes.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_a_magazine() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let magazine = jikan.find_magazine(1, 1).await.unwrap();
+    let magazine = JIKAN.find_magazine(1, 1).await.unwrap();
     assert!(magazine.mangas.len() > 0);
 }
 
 #[tokio::test]
 async fn should_find_an_user_profile() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let user_profile = jikan.find_user("Bruno319", UserInfo::Profile).await.unwrap();
+    let user_profile = JIKAN.find_user("Bruno319", UserInfo::Profile).await.unwrap();
     if let UserResultEnum::Profile(profile) = user_profile {
         assert_eq!(profile.username, "Bruno319");
     } else {
@@ -381,9 +311,7 @@ async fn should_find_an_user_profile() {
 
 #[tokio::test]
 async fn should_find_an_user_history() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let user_history = jikan.find_user("Bruno319", UserInfo::History { source: HistorySource::Both }).await.unwrap();
+    let user_history = JIKAN.find_user("Bruno319", UserInfo::History { source: HistorySource::Both }).await.unwrap();
     if let UserResultEnum::History(_history) = user_history {
         assert!(true);
     } else {
@@ -393,9 +321,7 @@ async fn should_find_an_user_history() {
 
 #[tokio::test]
 async fn should_find_user_friends() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let user_friends = jikan.find_user("Bruno319", UserInfo::Friends { page: 1 }).await.unwrap();
+    let user_friends = JIKAN.find_user("Bruno319", UserInfo::Friends { page: 1 }).await.unwrap();
     if let UserResultEnum::Friends(friends) = user_friends {
         assert!(friends.len() > 0);
     } else {
@@ -405,10 +331,8 @@ async fn should_find_user_friends() {
 
 #[tokio::test]
 async fn should_find_anime_list_from_user() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
     let query = AnimeListQuery::new();
-    let user_anime_list = jikan.find_user("Bruno319", UserInfo::Animelist { query }).await.unwrap();
+    let user_anime_list = JIKAN.find_user("Bruno319", UserInfo::Animelist { query }).await.unwrap();
     if let UserResultEnum::AnimeList(anime_list) = user_anime_list {
         assert!(anime_list.len() > 0);
     } else {
@@ -418,12 +342,10 @@ async fn should_find_anime_list_from_user() {
 
 #[tokio::test]
 async fn should_find_top_scored_animes_from_user() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
     let query = AnimeListQuery::new()
         .order_by(OrderAnimeListBy::Score)
         .sort(Sort::Descending);
-    let user_anime_list = jikan.find_user("Bruno319", UserInfo::Animelist { query }).await.unwrap();
+    let user_anime_list = JIKAN.find_user("Bruno319", UserInfo::Animelist { query }).await.unwrap();
     if let UserResultEnum::AnimeList(anime_list) = user_anime_list {
         assert!(anime_list.len() > 0);
     } else {
@@ -433,10 +355,8 @@ async fn should_find_top_scored_animes_from_user() {
 
 #[tokio::test]
 async fn should_find_manga_list_from_user() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
     let query = MangaListQuery::new();
-    let user_manga_list = jikan.find_user("Bruno319", UserInfo::Mangalist { query }).await.unwrap();
+    let user_manga_list = JIKAN.find_user("Bruno319", UserInfo::Mangalist { query }).await.unwrap();
     if let UserResultEnum::MangaList(manga_list) = user_manga_list {
         assert!(manga_list.len() > 0);
     } else {
@@ -446,12 +366,10 @@ async fn should_find_manga_list_from_user() {
 
 #[tokio::test]
 async fn should_find_top_scored_mangas_from_user() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
     let query = MangaListQuery::new()
         .order_by(OrderMangaListBy::Score)
         .sort(Sort::Descending);
-    let user_manga_list = jikan.find_user("Bruno319", UserInfo::Mangalist { query }).await.unwrap();
+    let user_manga_list = JIKAN.find_user("Bruno319", UserInfo::Mangalist { query }).await.unwrap();
     if let UserResultEnum::MangaList(manga_list) = user_manga_list {
         assert!(manga_list.len() > 0);
     } else {
@@ -461,32 +379,24 @@ async fn should_find_top_scored_mangas_from_user() {
 
 #[tokio::test]
 async fn should_find_a_club() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let club = jikan.find_club(1).await.unwrap();
+    let club = JIKAN.find_club(1).await.unwrap();
     assert_eq!(club.mal_id, 1);
 }
 
 #[tokio::test]
 async fn should_find_club_members() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let members = jikan.find_club_members(1, 1).await.unwrap();
+    let members = JIKAN.find_club_members(1, 1).await.unwrap();
     assert!(members.len() > 0);
 }
 
 #[tokio::test]
 async fn should_retrieve_api_status() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let status = jikan.retrieve_api_status().await.unwrap();
+    let status = JIKAN.retrieve_api_status().await.unwrap();
     assert!(status.requests_this_month > 0);
 }
 
 #[tokio::test]
 async fn should_retrieve_info_on_this_month_about_anime_endpoint() {
-    thread::sleep(Duration::from_secs(3));
-    let jikan = Jikan::new();
-    let endpoints = jikan.retrieve_request_info(InfoAbout::Anime, Period::Monthly, 1).await.unwrap();
+    let endpoints = JIKAN.retrieve_request_info(InfoAbout::Anime, Period::Monthly, 1).await.unwrap();
     assert!(endpoints.len() > 0);
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use jikan_rs::client::Jikan;
+use jikan_rs::transport::{FixtureTransport, Response};
+
+/// Builds a client answered by fixtures, each route responding with a 200 status and its body.
+///
+/// Franchise walks are not throttled, as the fixtures answer any burst of requests.
+pub fn jikan(routes: &[(&str, &str)]) -> (Jikan, Arc<FixtureTransport>) {
+    let fixtures = fixtures(routes);
+    let jikan = Jikan::builder()
+        .base_url("http://jikan.test/v3".to_string())
+        .transport(fixtures.clone())
+        .throttle_walks(false)
+        .build()
+        .unwrap();
+    (jikan, fixtures)
+}
+
+/// Same as `jikan`, the v4 API being served by the fixtures too.
+pub fn jikan_v4(routes: &[(&str, &str)]) -> (Jikan, Arc<FixtureTransport>) {
+    let fixtures = fixtures(routes);
+    let jikan = Jikan::builder()
+        .base_url("http://jikan.test/v3".to_string())
+        .v4_base_url("http://jikan.test/v4".to_string())
+        .transport(fixtures.clone())
+        .throttle_walks(false)
+        .build()
+        .unwrap();
+    (jikan, fixtures)
+}
+
+pub fn fixtures(routes: &[(&str, &str)]) -> Arc<FixtureTransport> {
+    let fixtures = Arc::new(FixtureTransport::new());
+    for (route, body) in routes {
+        fixtures.route(route, Response::new(200, body));
+    }
+    fixtures
+}
+
+pub fn requested_urls(fixtures: &FixtureTransport) -> Vec<String> {
+    fixtures.requests().into_iter().map(|request| request.url).collect()
+}
+
+/// Replaces the title and the related entries of the anime or manga fixture.
+pub fn with_related(fixture: &str, title: &str, related: &str) -> String {
+    let start = fixture.find("\"related\": {").unwrap();
+    let end = start + fixture[start..].find("\n  },").unwrap() + 4;
+    let title = format!("\"title\": {}", serde_json::to_string(title).unwrap());
+    format!("{}\"related\": {}{}", &fixture[..start], related, &fixture[end..])
+        .replacen("\"title\": \"Cowboy Bebop\"", &title, 1)
+        .replacen("\"title\": \"Monster\"", &title, 1)
+}
+
+/// Formats the related entries of a fixture, each relation pointing to a single entry.
+pub fn related(relations: &[(&str, &str, u32)]) -> String {
+    let relations: Vec<String> = relations.iter()
+        .map(|(kind, content_type, mal_id)| format!(r#""{}": [{}]"#, kind, item(content_type, *mal_id)))
+        .collect();
+    format!("{{{}}}", relations.join(", "))
+}
+
+pub fn item(content_type: &str, mal_id: u32) -> String {
+    format!(r#"{{"mal_id": {}, "type": "{}", "name": "", "url": ""}}"#, mal_id, content_type)
+}
@@ -0,0 +1,66 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::prelude::*;
+use jikan_rs::Error;
+
+use common::jikan;
+
+#[test]
+fn should_validate_dates() {
+    assert_eq!(Date::new(2020, 2, 29).unwrap().to_string(), "2020-02-29");
+    assert_eq!(Date::from_year_month(1998, 4).unwrap().to_string(), "1998-04");
+    assert_eq!(Date::from_year(1998).unwrap().to_string(), "1998");
+    assert!(Date::new(2021, 2, 29).is_err());
+    assert!(Date::new(1900, 2, 29).is_err());
+    assert!(Date::new(2020, 4, 31).is_err());
+    assert!(Date::new(2020, 13, 1).is_err());
+    assert!(Date::new(2020, 1, 0).is_err());
+    assert!(Date::from_year(0).is_err());
+    assert!(matches!(Date::new(2020, 13, 45), Err(Error::InvalidDate(_))));
+}
+
+#[test]
+fn should_parse_dates() {
+    let date: Date = "2020-01-05".parse().unwrap();
+    assert_eq!((date.year(), date.month(), date.day()), (2020, Some(1), Some(5)));
+    assert_eq!("1998-04".parse::<Date>().unwrap(), Date::from_year_month(1998, 4).unwrap());
+    assert_eq!("1998".parse::<Date>().unwrap(), Date::from_year(1998).unwrap());
+    for invalid in &["", "2020-", "2020-1-", "2020-02-30", "20201-01-01", "2020-01-05-01", "+2020", "2020/01/05", "abc"] {
+        assert!(invalid.parse::<Date>().is_err(), "{} should not parse", invalid);
+    }
+    assert!(Date::from_year(1998).unwrap() < Date::from_year_month(1998, 1).unwrap());
+}
+
+#[tokio::test]
+async fn should_send_dates_in_queries() {
+    let (jikan, fixtures) = jikan(&[]);
+    let query = SearchQueryBuilder::new(SearchSource::Anime)
+        .name("one")
+        .start_date("1999-10".parse().unwrap())
+        .end_date(Date::new(2000, 1, 5).unwrap());
+    assert!(jikan.search(query).await.is_err());
+
+    let query = AnimeListQuery::new()
+        .aired_from(Date::from_year(1998).unwrap())
+        .aired_to(Date::new(1999, 4, 24).unwrap());
+    assert!(jikan.find_user("Bruno319", UserInfo::Animelist { query }).await.is_err());
+
+    let urls: Vec<String> = fixtures.requests().into_iter().map(|request| request.url).collect();
+    assert_eq!(urls, vec![
+        "http://jikan.test/v3/search/anime?page=1&q=one&start_date=1999-10&end_date=2000-01-05",
+        "http://jikan.test/v3/user/Bruno319/animelist?page=1&sort=descending&aired_from=1998&aired_to=1999-04-24",
+    ]);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn should_convert_chrono_dates() {
+    use std::convert::TryFrom;
+
+    let date = chrono::NaiveDate::from_ymd_opt(2009, 4, 5).unwrap();
+    assert_eq!(Date::try_from(date).unwrap(), Date::new(2009, 4, 5).unwrap());
+    let date_time = chrono::DateTime::parse_from_rfc3339("1998-04-03T00:00:00+00:00").unwrap();
+    assert_eq!(Date::try_from(date_time).unwrap().to_string(), "1998-04-03");
+}
@@ -0,0 +1,102 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::prelude::*;
+#[cfg(feature = "chrono")]
+use jikan_rs::Error;
+
+use common::jikan;
+
+async fn top_anime(start_date: &str, end_date: &str) -> RankedAnime {
+    let body = include_str!("fixtures/top_anime.json")
+        .replace("\"Apr 2009\"", start_date)
+        .replace("\"Jul 2010\"", end_date);
+    let (jikan, _) = jikan(&[("/top/anime/1/", &body)]);
+    let top = jikan.find_top(Top::Anime { page: 1, subtype: TopAnimeSubtype::All }).await.unwrap();
+    match top {
+        TopResult::Anime(mut animes) => animes.remove(0),
+        _ => panic!(),
+    }
+}
+
+#[tokio::test]
+async fn should_keep_dates_as_sent() {
+    let (jikan, _) = jikan(&[("/anime/1", include_str!("fixtures/anime.json"))]);
+    let anime = jikan.find_anime(1).await.unwrap();
+    let from = anime.aired.from.unwrap();
+    assert_eq!(from.as_str(), "1998-04-03T00:00:00+00:00");
+    assert_eq!(from.date(), Some(Date::new(1998, 4, 3).unwrap()));
+}
+
+#[tokio::test]
+async fn should_keep_the_precision_of_partial_dates() {
+    let anime = top_anime("\"Apr 2009\"", "\"2010\"").await;
+    let start_date = anime.start_date.unwrap().date().unwrap();
+    assert_eq!(start_date, Date::from_year_month(2009, 4).unwrap());
+    assert_eq!(start_date.day(), None);
+    assert_eq!(anime.end_date.unwrap().date(), Some(Date::from_year(2010).unwrap()));
+
+    let anime = top_anime("\"Apr 3, 2009\"", "null").await;
+    assert_eq!(anime.start_date.unwrap().date(), Some(Date::new(2009, 4, 3).unwrap()));
+    assert!(anime.end_date.is_none());
+}
+
+#[tokio::test]
+async fn should_not_read_unknown_dates() {
+    let anime = top_anime("\"?\"", "\"Apr 31, 2009\"").await;
+    assert_eq!(anime.start_date.unwrap().date(), None);
+    assert_eq!(anime.end_date.unwrap().date(), None);
+}
+
+#[tokio::test]
+async fn should_serialize_dates_as_sent() {
+    let anime = top_anime("\"Apr 2009\"", "null").await;
+    let json = serde_json::to_value(&anime).unwrap();
+    assert_eq!(json["start_date"], "Apr 2009");
+    assert!(json["end_date"].is_null());
+}
+
+#[test]
+fn should_order_dates_by_their_parsed_value() {
+    let mut dates: Vec<DateTime> = ["Jan 2, 2010", "2009", "?", "1998-04-03T00:00:00+00:00", "Apr 2009", "Apr 3, 2009"]
+        .iter()
+        .map(|date| DateTime::from(date.to_string()))
+        .collect();
+    dates.sort();
+    let dates: Vec<&str> = dates.iter().map(DateTime::as_str).collect();
+    assert_eq!(dates, ["?", "1998-04-03T00:00:00+00:00", "2009", "Apr 2009", "Apr 3, 2009", "Jan 2, 2010"]);
+    assert!(DateTime::from("Dec 2009".to_string()) < DateTime::from("2010-01-01T00:00:00+00:00".to_string()));
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn should_convert_dates_to_chrono() {
+    use chrono::{Datelike, Timelike};
+
+    let (jikan, _) = jikan(&[("/anime/1", include_str!("fixtures/anime.json"))]);
+    let from = jikan.find_anime(1).await.unwrap().aired.from.unwrap().to_chrono().unwrap();
+    assert_eq!((from.year(), from.month(), from.day(), from.hour()), (1998, 4, 3, 0));
+    assert_eq!(from.offset().local_minus_utc(), 0);
+
+    let anime = top_anime("\"Apr 3, 2009\"", "\"Apr 2009\"").await;
+    let start_date = anime.start_date.unwrap();
+    let start = start_date.to_chrono().unwrap();
+    assert_eq!((start.year(), start.month(), start.day(), start.hour()), (2009, 4, 3, 0));
+    assert_eq!(start.offset().local_minus_utc(), 0);
+    assert_eq!(start_date.to_naive_date().unwrap(), chrono::NaiveDate::from_ymd_opt(2009, 4, 3).unwrap());
+}
+
+#[cfg(feature = "chrono")]
+#[tokio::test]
+async fn should_say_why_dates_cannot_be_converted() {
+    let anime = top_anime("\"Apr 2009\"", "\"?\"").await;
+    let start_date = anime.start_date.unwrap();
+    match start_date.to_chrono() {
+        Err(Error::UnconvertibleDate { date, reason }) => assert_eq!((date.as_str(), reason), ("Apr 2009", "no day given")),
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert!(start_date.to_naive_date().is_err());
+    let error = anime.end_date.unwrap().to_naive_date().unwrap_err();
+    assert_eq!(error.to_string(), "Unable to convert the date '?': unknown format");
+}
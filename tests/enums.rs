@@ -0,0 +1,79 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::prelude::*;
+
+use common::jikan;
+
+#[tokio::test]
+async fn should_parse_enum_fields() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1", include_str!("fixtures/anime.json")),
+        ("/manga/1", include_str!("fixtures/manga.json")),
+        ("/search/anime?page=1&q=one", include_str!("fixtures/search_anime.json")),
+    ]);
+    let anime = jikan.find_anime(1).await.unwrap();
+    assert_eq!(anime.anime_type, AnimeType::TV);
+    assert_eq!(anime.source, AnimeSource::Original);
+    assert_eq!(anime.status, AnimeStatus::Completed);
+    assert_eq!(anime.rating, Some(Rating::R17));
+
+    let manga = jikan.find_manga(1).await.unwrap();
+    assert_eq!(manga.manga_type, MangaType::Manga);
+    assert_eq!(manga.status, MangaStatus::Completed);
+
+    match jikan.search(SearchQueryBuilder::new(SearchSource::Anime).name("one")).await.unwrap() {
+        SearchResultEnum::Anime(result) => assert_eq!(result.results[0].rated, Some(Rating::Pg13)),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn should_keep_unknown_values() {
+    let anime_type: AnimeType = serde_json::from_str("\"TV Special\"").unwrap();
+    assert_eq!(anime_type, AnimeType::Unknown("TV Special".to_string()));
+    assert_eq!(serde_json::to_string(&anime_type).unwrap(), "\"TV Special\"");
+
+    let status: MangaStatus = serde_json::from_str("\"On Hiatus\"").unwrap();
+    assert_eq!(status, MangaStatus::Unknown("On Hiatus".to_string()));
+    assert_eq!(serde_json::to_string(&MangaStatus::ToBePublished).unwrap(), "\"Not yet published\"");
+    assert_eq!(serde_json::to_string(&AnimeSource::FourKomaManga).unwrap(), "\"4-koma manga\"");
+}
+
+#[tokio::test]
+async fn should_send_unknown_values_in_queries() {
+    let (jikan, fixtures) = jikan(&[]);
+    let query = SearchQueryBuilder::new(SearchSource::Anime)
+        .name("one")
+        .status(SourceStatus::Anime(AnimeStatus::Unknown("upcoming".to_string())));
+    assert!(jikan.search(query).await.is_err());
+    assert_eq!(fixtures.requests()[0].url, "http://jikan.test/v3/search/anime?page=1&q=one&status=upcoming");
+}
+
+#[tokio::test]
+async fn should_encode_unknown_values_in_queries() {
+    let (jikan, fixtures) = jikan(&[]);
+    let query = SearchQueryBuilder::new(SearchSource::Manga)
+        .name("one")
+        .status(SourceStatus::Manga(MangaStatus::Unknown("On Hiatus".to_string())));
+    assert!(jikan.search(query).await.is_err());
+    assert_eq!(fixtures.requests()[0].url, "http://jikan.test/v3/search/manga?page=1&q=one&status=On%20Hiatus");
+}
+
+#[tokio::test]
+async fn should_send_type_filters_under_the_type_key() {
+    let (jikan, fixtures) = jikan(&[]);
+    let query = SearchQueryBuilder::new(SearchSource::Anime)
+        .name("one")
+        .type_source(SearchSourceType::Anime(AnimeType::Movie));
+    assert!(jikan.search(query).await.is_err());
+    let query = SearchQueryBuilder::new(SearchSource::Manga)
+        .name("one")
+        .type_source(SearchSourceType::Manga(MangaType::Novel));
+    assert!(jikan.search(query).await.is_err());
+    assert_eq!(common::requested_urls(&fixtures), vec![
+        "http://jikan.test/v3/search/anime?page=1&q=one&type=movie",
+        "http://jikan.test/v3/search/manga?page=1&q=one&type=novel",
+    ]);
+}
@@ -0,0 +1,77 @@
+extern crate jikan_rs;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use futures::StreamExt;
+
+use jikan_rs::client::Jikan;
+use jikan_rs::Result;
+use jikan_rs::transport::{BoxFuture, FixtureTransport, Request, Response, Transport};
+
+fn episodes_page(page: u16, last_page: u16) -> String {
+    let episode = page as u32 * 100;
+    format!(r#"{{
+        "request_hash": "request:episodes:{page}", "request_cached": false, "request_cache_expiry": 43200,
+        "episodes_last_page": {last_page},
+        "episodes": [{{"episode_id": {episode}, "title": "Episode {episode}", "title_japanese": null, "title_romanji": null,
+                       "aired": null, "filler": false, "recap": false, "video_url": null, "forum_url": null}}]
+    }}"#, page = page, last_page = last_page, episode = episode)
+}
+
+/// Serves episode pages slowly, later pages faster, recording how many requests overlap.
+struct SlowEpisodes {
+    last_page: u16,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+impl Transport for SlowEpisodes {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<Response>> {
+        Box::pin(async move {
+            let page: u16 = request.url.rsplit('/').next().unwrap().parse().unwrap();
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::delay_for(Duration::from_millis(50 / page as u64)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(Response::new(200, &episodes_page(page, self.last_page)))
+        })
+    }
+}
+
+#[tokio::test]
+async fn should_fetch_episode_pages_concurrently_in_order() {
+    let transport = Arc::new(SlowEpisodes { last_page: 8, in_flight: AtomicUsize::new(0), max_in_flight: AtomicUsize::new(0) });
+    let jikan = Jikan::builder()
+        .transport(transport.clone())
+        .concurrency(3)
+        .build()
+        .unwrap();
+
+    let episodes = jikan.find_episodes(21).await.unwrap();
+
+    let ids: Vec<u32> = episodes.iter().map(|episode| episode.episode_id).collect();
+    assert_eq!(ids, vec![100, 200, 300, 400, 500, 600, 700, 800]);
+    assert_eq!(transport.max_in_flight.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn should_support_more_than_255_episode_pages() {
+    let fixtures = Arc::new(FixtureTransport::new());
+    fixtures.route("/anime/21/episodes/300", Response::new(200, &episodes_page(300, 301)));
+    fixtures.route("/anime/21/episodes/301", Response::new(200, &episodes_page(301, 301)));
+    let jikan = Jikan::builder()
+        .transport(fixtures.clone())
+        .build()
+        .unwrap();
+
+    let page = jikan.find_episodes_page(21, 300).await.unwrap();
+    assert_eq!(page.number, 300);
+    assert_eq!(page.last_page, Some(301));
+    assert_eq!(page.items[0].episode_id, 30000);
+
+    let episodes: Vec<_> = jikan.stream_episodes(21, 300).collect().await;
+    assert_eq!(episodes.len(), 2);
+    assert_eq!(fixtures.requests().len(), 3);
+}
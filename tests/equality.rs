@@ -0,0 +1,67 @@
+extern crate jikan_rs;
+
+mod common;
+
+use std::collections::HashSet;
+
+use jikan_rs::prelude::*;
+
+use common::jikan;
+
+#[tokio::test]
+async fn should_compare_entities_by_payload() {
+    let (jikan, _) = jikan(&[("/anime/1", include_str!("fixtures/anime.json"))]);
+    let anime = jikan.find_anime(1).await.unwrap();
+    let same_anime = jikan.bypass_cache().find_anime(1).await.unwrap();
+    assert_eq!(anime, same_anime);
+
+    let mut edited = anime.clone();
+    assert_eq!(edited, anime);
+    edited.title_english = None;
+    assert_ne!(edited, anime);
+}
+
+// The client embedded in entities is mutable but takes no part in their hash
+#[allow(clippy::mutable_key_type)]
+#[tokio::test]
+async fn should_ignore_response_metadata() {
+    let cached = include_str!("fixtures/character.json")
+        .replace("\"request_cached\": false", "\"request_cached\": true")
+        .replace("\"request_cache_expiry\": 43200", "\"request_cache_expiry\": 1200");
+    let (jikan, _) = jikan(&[
+        ("/character/1", include_str!("fixtures/character.json")),
+        ("/character/2", &cached),
+    ]);
+    let fresh = jikan.find_character(1).await.unwrap();
+    let cached = jikan.find_character(2).await.unwrap();
+    assert_ne!(fresh.meta(), cached.meta());
+    assert_eq!(fresh, cached);
+    let characters: HashSet<Character> = vec![fresh, cached].into_iter().collect();
+    assert_eq!(characters.len(), 1);
+}
+
+// The client embedded in entities is mutable but takes no part in their hash
+#[allow(clippy::mutable_key_type)]
+#[tokio::test]
+async fn should_hash_id_bearing_items() {
+    let (jikan, _) = jikan(&[
+        ("/character/1", include_str!("fixtures/character.json")),
+        ("/anime/1", include_str!("fixtures/anime.json")),
+    ]);
+    let mut characters = HashSet::new();
+    characters.insert(jikan.find_character(1).await.unwrap());
+    characters.insert(jikan.find_character(1).await.unwrap());
+    assert_eq!(characters.len(), 1);
+
+    let anime = jikan.find_anime(1).await.unwrap();
+    let genres: HashSet<MALTypeItem> = anime.genres.iter().cloned().chain(anime.genres.clone()).collect();
+    assert_eq!(genres.len(), anime.genres.len());
+}
+
+#[test]
+fn should_use_queries_as_keys() {
+    let seasons: HashSet<Season> = vec![Season::Spring(2020), Season::Spring(2020), Season::Later].into_iter().collect();
+    assert_eq!(seasons.len(), 2);
+    assert_eq!(SourceType::Anime(AnimeId(1)), SourceType::Anime(AnimeId(1)));
+    assert_ne!(SourceType::Anime(AnimeId(1)), SourceType::Manga(MangaId(1)));
+}
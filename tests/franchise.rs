@@ -0,0 +1,171 @@
+extern crate jikan_rs;
+
+mod common;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use jikan_rs::client::Jikan;
+use jikan_rs::prelude::*;
+use jikan_rs::transport::FixtureTransport;
+
+use common::{item, requested_urls, with_related};
+
+fn anime(title: &str, related: &str) -> String {
+    with_related(include_str!("fixtures/anime.json"), title, related)
+}
+
+fn manga(title: &str, related: &str) -> String {
+    with_related(include_str!("fixtures/manga.json"), title, related)
+}
+
+fn routes() -> Vec<(&'static str, String)> {
+    vec![
+        ("/anime/1", anime("Bebop", &format!(r#"{{"Sequel": [{}]}}"#, item("anime", 2)))),
+        ("/anime/2", anime("Bebop \"Movie\"", &format!(r#"{{"Prequel": [{}], "Adaptation": [{}], "Character": [{}]}}"#,
+                                                   item("anime", 1), item("manga", 3), item("anime", 9)))),
+        ("/manga/3", manga("Bebop Manga", &format!(r#"{{"Adaptation": [{}], "Side story": [{}]}}"#,
+                                                   item("anime", 1), item("anime", 404)))),
+    ]
+}
+
+fn jikan() -> (Jikan, Arc<FixtureTransport>) {
+    let routes = routes();
+    let routes: Vec<(&str, &str)> = routes.iter().map(|(route, body)| (*route, body.as_str())).collect();
+    common::jikan(&routes)
+}
+
+/// Same as `jikan`, franchise walks being throttled as they are against Jikan.
+fn throttled_jikan() -> (Jikan, Arc<FixtureTransport>) {
+    let routes = routes();
+    let routes: Vec<(&str, &str)> = routes.iter().map(|(route, body)| (*route, body.as_str())).collect();
+    let fixtures = common::fixtures(&routes);
+    let jikan = Jikan::builder()
+        .base_url("http://jikan.test/v3".to_string())
+        .transport(fixtures.clone())
+        .build()
+        .unwrap();
+    (jikan, fixtures)
+}
+
+#[tokio::test]
+async fn should_walk_the_franchise() {
+    let (jikan, fixtures) = jikan();
+    let franchise = jikan.find_franchise(EntityRef::Anime(AnimeId(1)), FranchiseQuery::new()).await.unwrap();
+    let entities: Vec<EntityRef> = franchise.entries.iter().map(|entry| entry.entity).collect();
+    assert_eq!(entities, vec![EntityRef::Anime(AnimeId(1)), EntityRef::Anime(AnimeId(2)), EntityRef::Manga(MangaId(3))]);
+    assert_eq!(franchise.entry(EntityRef::Manga(MangaId(3))).unwrap().title, "Bebop Manga");
+    assert!(!franchise.truncated);
+    assert_eq!(franchise.edges, vec![
+        FranchiseEdge { from: EntityRef::Anime(AnimeId(1)), to: EntityRef::Anime(AnimeId(2)), relation: RelationKind::Sequel },
+        FranchiseEdge { from: EntityRef::Anime(AnimeId(2)), to: EntityRef::Anime(AnimeId(1)), relation: RelationKind::Prequel },
+        FranchiseEdge { from: EntityRef::Anime(AnimeId(2)), to: EntityRef::Manga(MangaId(3)), relation: RelationKind::Adaptation },
+        FranchiseEdge { from: EntityRef::Manga(MangaId(3)), to: EntityRef::Anime(AnimeId(1)), relation: RelationKind::Adaptation },
+    ]);
+    assert_eq!(franchise.relations_of(EntityRef::Anime(AnimeId(2))).count(), 2);
+    // Every entry is fetched once, the deleted side story included
+    assert_eq!(requested_urls(&fixtures), vec![
+        "http://jikan.test/v3/anime/1",
+        "http://jikan.test/v3/anime/2",
+        "http://jikan.test/v3/manga/3",
+        "http://jikan.test/v3/anime/404",
+    ]);
+}
+
+#[tokio::test]
+async fn should_stop_at_max_entries() {
+    let (jikan, fixtures) = jikan();
+    let query = FranchiseQuery::new().max_entries(2);
+    let franchise = jikan.find_franchise(EntityRef::Anime(AnimeId(1)), query).await.unwrap();
+    assert_eq!(franchise.entries.len(), 2);
+    assert!(franchise.truncated);
+    assert!(franchise.edges.iter().all(|edge| edge.to != EntityRef::Manga(MangaId(3))));
+    assert_eq!(requested_urls(&fixtures).len(), 2);
+}
+
+#[tokio::test]
+async fn should_follow_the_requested_relations() {
+    let (jikan, _) = jikan();
+    let query = FranchiseQuery::new().relations(vec![RelationKind::Sequel, RelationKind::Prequel]);
+    let franchise = jikan.find_franchise(EntityRef::Anime(AnimeId(1)), query).await.unwrap();
+    assert_eq!(franchise.entries.len(), 2);
+    assert!(!franchise.truncated);
+}
+
+#[tokio::test]
+async fn should_fail_on_a_missing_seed() {
+    let (jikan, _) = jikan();
+    let err = jikan.find_franchise(EntityRef::Manga(MangaId(1)), FranchiseQuery::new()).await.unwrap_err();
+    assert!(err.is_not_found());
+}
+
+#[tokio::test]
+async fn should_export_the_franchise() {
+    let (jikan, _) = jikan();
+    let franchise = jikan.find_anime(1).await.unwrap().get_franchise(FranchiseQuery::new()).await.unwrap();
+    assert_eq!(franchise.to_dot(), concat!(
+        "digraph franchise {\n",
+        "    \"anime/1\" [label=\"Bebop\"];\n",
+        "    \"anime/2\" [label=\"Bebop \\\"Movie\\\"\"];\n",
+        "    \"manga/3\" [label=\"Bebop Manga\"];\n",
+        "    \"anime/1\" -> \"anime/2\" [label=\"Sequel\"];\n",
+        "    \"anime/2\" -> \"anime/1\" [label=\"Prequel\"];\n",
+        "    \"anime/2\" -> \"manga/3\" [label=\"Adaptation\"];\n",
+        "    \"manga/3\" -> \"anime/1\" [label=\"Adaptation\"];\n",
+        "}\n",
+    ));
+    let json: serde_json::Value = serde_json::from_str(&franchise.to_json()).unwrap();
+    assert_eq!(json["seed"]["Anime"], 1);
+    assert_eq!(json["entries"][2]["entity"]["Manga"], 3);
+    assert_eq!(json["edges"][0]["relation"], "Sequel");
+    assert_eq!(franchise.to_json(), jikan.find_franchise(EntityRef::Anime(AnimeId(1)), FranchiseQuery::new()).await.unwrap().to_json());
+}
+
+#[tokio::test]
+async fn should_seed_the_walk_with_the_entry() {
+    let (jikan, fixtures) = jikan();
+    let anime = jikan.find_anime(1).await.unwrap();
+    let franchise = anime.get_franchise(FranchiseQuery::new()).await.unwrap();
+    assert_eq!(franchise.entries.len(), 3);
+    assert_eq!(requested_urls(&fixtures), vec![
+        "http://jikan.test/v3/anime/1",
+        "http://jikan.test/v3/anime/2",
+        "http://jikan.test/v3/manga/3",
+        "http://jikan.test/v3/anime/404",
+    ]);
+}
+
+#[tokio::test]
+async fn should_throttle_a_client_without_rate_limiter() {
+    let (jikan, fixtures) = throttled_jikan();
+    let start = Instant::now();
+    jikan.find_franchise(EntityRef::Anime(AnimeId(1)), FranchiseQuery::new()).await.unwrap();
+    // The default limiter allows 2 requests at once then one every half second
+    assert_eq!(fixtures.requests().len(), 4);
+    assert!(start.elapsed() >= Duration::from_millis(900));
+}
+
+#[tokio::test]
+async fn should_share_the_default_limiter_between_walks() {
+    let (jikan, fixtures) = throttled_jikan();
+    let other = jikan.clone();
+    let start = Instant::now();
+    let (first, second) = futures::join!(
+        jikan.find_franchise(EntityRef::Anime(AnimeId(1)), FranchiseQuery::new()),
+        other.find_franchise(EntityRef::Anime(AnimeId(2)), FranchiseQuery::new()),
+    );
+    first.unwrap();
+    second.unwrap();
+    // 8 requests within one budget: 2 at once then one every half second
+    assert_eq!(fixtures.requests().len(), 8);
+    assert!(start.elapsed() >= Duration::from_millis(2900));
+}
+
+#[tokio::test]
+async fn should_not_throttle_walks_when_disabled() {
+    let (jikan, fixtures) = jikan();
+    let start = Instant::now();
+    jikan.find_franchise(EntityRef::Anime(AnimeId(1)), FranchiseQuery::new()).await.unwrap();
+    assert_eq!(fixtures.requests().len(), 4);
+    assert!(start.elapsed() < Duration::from_millis(400));
+}
@@ -0,0 +1,42 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::prelude::*;
+
+use common::jikan;
+
+#[tokio::test]
+async fn should_accept_typed_and_raw_ids() {
+    let (jikan, _) = jikan(&[("/anime/1", include_str!("fixtures/anime.json"))]);
+    let from_id = jikan.find_anime(AnimeId(1)).await.unwrap();
+    let from_u32 = jikan.find_anime(1).await.unwrap();
+    assert_eq!(from_id.mal_id, AnimeId(1));
+    assert_eq!(from_id, from_u32);
+    assert_eq!(u32::from(from_id.mal_id), 1);
+}
+
+#[tokio::test]
+async fn should_resolve_items_to_typed_refs() {
+    let (jikan, _) = jikan(&[("/anime/1", include_str!("fixtures/anime.json"))]);
+    let anime = jikan.find_anime(1).await.unwrap();
+    let (_, adaptation) = anime.related.iter().next().unwrap();
+    assert_eq!(adaptation.source(), Some(SourceType::Manga(MangaId(173))));
+    assert_eq!(anime.related.entities().next(), Some((&RelationKind::Adaptation, EntityRef::Manga(MangaId(173)))));
+
+    assert_eq!(anime.studios[0].source(), None);
+    assert_eq!(anime.licensors[0].source(), None);
+    assert_eq!(anime.genres[0].source(), None);
+
+    let mut person = adaptation.clone();
+    person.url = "https://myanimelist.net/people/1/Tomokazu_Seki".to_string();
+    assert_eq!(person.source(), Some(SourceType::Person(PersonId(1))));
+}
+
+#[test]
+fn should_serialize_ids_as_integers() {
+    assert_eq!(serde_json::to_string(&CharacterId(42)).unwrap(), "42");
+    assert_eq!(serde_json::from_str::<ClubId>("7").unwrap(), ClubId::from(7));
+    assert_eq!(ProducerId(3).to_string(), "3");
+    assert_eq!(EntityRef::from(AnimeId(5)).mal_id(), 5);
+}
@@ -0,0 +1,315 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::prelude::*;
+
+use common::{jikan, requested_urls};
+
+#[tokio::test]
+async fn should_find_an_anime_offline() {
+    let (jikan, fixtures) = jikan(&[("/anime/1", include_str!("fixtures/anime.json"))]);
+    let anime = jikan.find_anime(1).await.unwrap();
+    assert_eq!(anime.mal_id, 1);
+    assert_eq!(anime.title, "Cowboy Bebop");
+    assert_eq!(requested_urls(&fixtures), vec!["http://jikan.test/v3/anime/1"]);
+}
+
+#[tokio::test]
+async fn should_find_a_manga_offline() {
+    let (jikan, _) = jikan(&[("/manga/1", include_str!("fixtures/manga.json"))]);
+    let manga = jikan.find_manga(1).await.unwrap();
+    assert_eq!(manga.mal_id, 1);
+    assert_eq!(manga.title, "Monster");
+}
+
+#[tokio::test]
+async fn should_find_a_person_offline() {
+    let (jikan, _) = jikan(&[("/person/1", include_str!("fixtures/person.json"))]);
+    let person = jikan.find_person(1).await.unwrap();
+    assert_eq!(person.name, "Tomokazu Seki");
+}
+
+#[tokio::test]
+async fn should_find_a_character_offline() {
+    let (jikan, _) = jikan(&[("/character/1", include_str!("fixtures/character.json"))]);
+    let character = jikan.find_character(1).await.unwrap();
+    assert_eq!(character.name, "Spike Spiegel");
+}
+
+#[tokio::test]
+async fn should_find_anime_and_manga_characters_offline() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1/characters_staff", include_str!("fixtures/anime_characters_staff.json")),
+        ("/manga/1/characters", include_str!("fixtures/manga_characters.json")),
+    ]);
+    let characters_staff = jikan.find_anime_characters(1).await.unwrap();
+    assert_eq!(characters_staff.characters.len(), 1);
+    assert_eq!(characters_staff.staff.len(), 1);
+    let characters = jikan.find_manga_characters(1).await.unwrap();
+    assert_eq!(characters.len(), 1);
+}
+
+#[tokio::test]
+async fn should_find_anime_sections_offline() {
+    let (jikan, fixtures) = jikan(&[
+        ("/anime/1", include_str!("fixtures/anime.json")),
+        ("/anime/1/characters_staff", include_str!("fixtures/anime_characters_staff.json")),
+        ("/v4/anime/1/relations", include_str!("fixtures/v4/anime_relations.json")),
+        ("/v4/anime/1/themes", include_str!("fixtures/v4/anime_themes.json")),
+        ("/v4/anime/1/external", include_str!("fixtures/v4/anime_external.json")),
+        ("/v4/anime/1/streaming", include_str!("fixtures/v4/anime_streaming.json")),
+    ]);
+    let staff = jikan.find_anime_staff(1).await.unwrap();
+    assert_eq!(staff.len(), 1);
+    let relations = jikan.find_anime_relations(1).await.unwrap();
+    assert_eq!(relations[1].relation, RelationKind::SideStory);
+    assert_eq!(relations[1].entries[0].mal_id, 5);
+    let themes = jikan.find_anime_themes(1).await.unwrap();
+    assert_eq!(themes.openings.len(), 1);
+    assert_eq!(themes.endings.len(), 2);
+
+    let anime = jikan.find_anime(1).await.unwrap();
+    let external_links = anime.get_external_links().await.unwrap();
+    assert_eq!(external_links[0].name, "Official Site");
+    let streaming = anime.get_streaming().await.unwrap();
+    assert_eq!(streaming[1].name, "Netflix");
+    assert_eq!(requested_urls(&fixtures)[1..], [
+        "https://api.jikan.moe/v4/anime/1/relations",
+        "https://api.jikan.moe/v4/anime/1/themes",
+        "http://jikan.test/v3/anime/1",
+        "https://api.jikan.moe/v4/anime/1/external",
+        "https://api.jikan.moe/v4/anime/1/streaming",
+    ]);
+}
+
+#[tokio::test]
+async fn should_follow_every_episodes_page_offline() {
+    let (jikan, fixtures) = jikan(&[
+        ("/anime/1/episodes/1", include_str!("fixtures/episodes_1.json")),
+        ("/anime/1/episodes/2", include_str!("fixtures/episodes_2.json")),
+    ]);
+    let episodes = jikan.find_episodes(1).await.unwrap();
+    assert_eq!(episodes.len(), 3);
+    assert_eq!(requested_urls(&fixtures), vec![
+        "http://jikan.test/v3/anime/1/episodes/1",
+        "http://jikan.test/v3/anime/1/episodes/2",
+    ]);
+}
+
+#[tokio::test]
+async fn should_find_source_resources_offline() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1/news", include_str!("fixtures/news.json")),
+        ("/manga/1/pictures", include_str!("fixtures/pictures.json")),
+        ("/anime/1/videos", include_str!("fixtures/videos.json")),
+        ("/manga/1/forum", include_str!("fixtures/forum.json")),
+        ("/anime/1/moreinfo", include_str!("fixtures/moreinfo.json")),
+        ("/anime/1/recommendations", include_str!("fixtures/recommendations.json")),
+    ]);
+    assert_eq!(jikan.find_news(SourceType::Anime(AnimeId(1))).await.unwrap().len(), 1);
+    assert_eq!(jikan.find_pictures(SourceType::Manga(MangaId(1))).await.unwrap().len(), 1);
+    let videos = jikan.find_videos(1).await.unwrap();
+    assert_eq!(videos.promo.len(), 1);
+    assert_eq!(videos.episodes.len(), 1);
+    assert_eq!(jikan.find_forum(SourceType::Manga(MangaId(1))).await.unwrap().len(), 1);
+    assert!(jikan.find_more_info(SourceType::Anime(AnimeId(1))).await.unwrap().is_some());
+    assert_eq!(jikan.find_recommendations(SourceType::Anime(AnimeId(1))).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn should_find_stats_offline() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1/stats", include_str!("fixtures/anime_stats.json")),
+        ("/manga/1/stats", include_str!("fixtures/manga_stats.json")),
+    ]);
+    match jikan.find_stats(SourceType::Anime(AnimeId(1))).await.unwrap() {
+        Stats::Anime(stats) => assert_eq!(stats.total, 1080959),
+        _ => panic!(),
+    }
+    match jikan.find_stats(SourceType::Manga(MangaId(1))).await.unwrap() {
+        Stats::Manga(stats) => assert_eq!(stats.total, 129945),
+        _ => panic!(),
+    }
+}
+
+#[tokio::test]
+async fn should_find_reviews_and_user_updates_offline() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1/reviews/1", include_str!("fixtures/anime_reviews.json")),
+        ("/manga/1/reviews/1", include_str!("fixtures/manga_reviews.json")),
+        ("/anime/1/userupdates/1", include_str!("fixtures/anime_userupdates.json")),
+        ("/manga/1/userupdates/1", include_str!("fixtures/manga_userupdates.json")),
+    ]);
+    assert!(matches!(jikan.find_reviews(SourceType::Anime(AnimeId(1)), 1).await.unwrap(), Reviews::Anime(_)));
+    assert!(matches!(jikan.find_reviews(SourceType::Manga(MangaId(1)), 1).await.unwrap(), Reviews::Manga(_)));
+    match jikan.find_user_updates(SourceType::Anime(AnimeId(1)), 1).await.unwrap() {
+        UserUpdates::Anime(updates) => assert_eq!(updates.len(), 2),
+        _ => panic!(),
+    }
+    match jikan.find_user_updates(SourceType::Manga(MangaId(1)), 1).await.unwrap() {
+        UserUpdates::Manga(updates) => assert_eq!(updates.len(), 1),
+        _ => panic!(),
+    }
+}
+
+#[tokio::test]
+async fn should_find_seasons_and_schedule_offline() {
+    let (jikan, _) = jikan(&[
+        ("/season/2020/spring", include_str!("fixtures/season.json")),
+        ("/season/archive", include_str!("fixtures/season_archive.json")),
+        ("/schedule/sunday", include_str!("fixtures/schedule.json")),
+    ]);
+    let season = jikan.find_season(Season::Spring(2020)).await.unwrap();
+    assert_eq!(season.animes.len(), 1);
+    assert_eq!(jikan.find_season_archives().await.unwrap().len(), 2);
+    let schedule = jikan.find_schedule(ScheduleOn::Sunday).await.unwrap();
+    assert_eq!(schedule.sunday.len(), 1);
+    assert!(schedule.monday.is_empty());
+}
+
+#[tokio::test]
+async fn should_find_top_offline() {
+    let (jikan, _) = jikan(&[
+        ("/top/anime/1/", include_str!("fixtures/top_anime.json")),
+        ("/top/manga/1/", include_str!("fixtures/top_manga.json")),
+        ("/top/characters/1", include_str!("fixtures/top_characters.json")),
+        ("/top/people/1", include_str!("fixtures/top_people.json")),
+    ]);
+    let top_anime = jikan.find_top(Top::Anime { page: 1, subtype: TopAnimeSubtype::All }).await.unwrap();
+    assert!(matches!(top_anime, TopResult::Anime(_)));
+    let top_manga = jikan.find_top(Top::Manga { page: 1, subtype: TopMangaSubtype::All }).await.unwrap();
+    assert!(matches!(top_manga, TopResult::Manga(_)));
+    assert!(matches!(jikan.find_top(Top::Character(1)).await.unwrap(), TopResult::Character(_)));
+    assert!(matches!(jikan.find_top(Top::People(1)).await.unwrap(), TopResult::People(_)));
+}
+
+#[tokio::test]
+async fn should_find_genres_producers_and_magazines_offline() {
+    let (jikan, _) = jikan(&[
+        ("/genre/anime/2/1", include_str!("fixtures/genre_anime.json")),
+        ("/genre/manga/25/1", include_str!("fixtures/genre_manga.json")),
+        ("/producer/1/1", include_str!("fixtures/producer.json")),
+        ("/magazine/1/1", include_str!("fixtures/magazine.json")),
+    ]);
+    let adventure_animes = jikan.find_animes_with_genre(AnimeGenre::Adventure, 1).await.unwrap();
+    assert_eq!(adventure_animes.mal_url.name, "Adventure Anime");
+    let shoujo_mangas = jikan.find_mangas_with_genre(MangaGenre::Shoujo, 1).await.unwrap();
+    assert_eq!(shoujo_mangas.mal_url.name, "Shoujo Manga");
+    assert_eq!(jikan.find_producer(1, 1).await.unwrap().animes.len(), 1);
+    assert_eq!(jikan.find_magazine(1, 1).await.unwrap().mangas.len(), 1);
+}
+
+#[tokio::test]
+async fn should_find_clubs_offline() {
+    let (jikan, _) = jikan(&[
+        ("/club/1", include_str!("fixtures/club.json")),
+        ("/club/1/members/1", include_str!("fixtures/club_members.json")),
+    ]);
+    assert_eq!(jikan.find_club(1).await.unwrap().mal_id, 1);
+    assert_eq!(jikan.find_club_members(1, 1).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn should_find_user_resources_offline() {
+    let (jikan, fixtures) = jikan(&[
+        ("/user/Bruno319/profile", include_str!("fixtures/user_profile.json")),
+        ("/user/Bruno319/history/", include_str!("fixtures/user_history.json")),
+        ("/user/Bruno319/friends/1", include_str!("fixtures/user_friends.json")),
+        ("/user/Bruno319/animelist?page=1&sort=descending", include_str!("fixtures/user_animelist.json")),
+        ("/user/Bruno319/mangalist?page=1&sort=descending", include_str!("fixtures/user_mangalist.json")),
+    ]);
+    match jikan.find_user("Bruno319", UserInfo::Profile).await.unwrap() {
+        UserResultEnum::Profile(profile) => assert_eq!(profile.username, "Bruno319"),
+        _ => panic!(),
+    }
+    let history = jikan.find_user("Bruno319", UserInfo::History { source: HistorySource::Both }).await.unwrap();
+    assert!(matches!(history, UserResultEnum::History(_)));
+    let friends = jikan.find_user("Bruno319", UserInfo::Friends { page: 1 }).await.unwrap();
+    assert!(matches!(friends, UserResultEnum::Friends(_)));
+    let anime_list = jikan.find_user("Bruno319", UserInfo::Animelist { query: AnimeListQuery::new() }).await.unwrap();
+    assert!(matches!(anime_list, UserResultEnum::AnimeList(_)));
+    let manga_list = jikan.find_user("Bruno319", UserInfo::Mangalist { query: MangaListQuery::new() }).await.unwrap();
+    assert!(matches!(manga_list, UserResultEnum::MangaList(_)));
+    assert_eq!(fixtures.requests().len(), 5);
+}
+
+#[tokio::test]
+async fn should_search_offline() {
+    let (jikan, _) = jikan(&[
+        ("/search/anime?page=1&q=one", include_str!("fixtures/search_anime.json")),
+        ("/search/character?page=1&q=lelouch", include_str!("fixtures/search_character.json")),
+    ]);
+    let query = SearchQueryBuilder::new(SearchSource::Anime).name("one");
+    match jikan.search(query).await.unwrap() {
+        SearchResultEnum::Anime(result) => assert_eq!(result.results.len(), 1),
+        _ => panic!(),
+    }
+    let query = SearchQueryBuilder::new(SearchSource::Character).name("lelouch");
+    assert!(matches!(jikan.search(query).await.unwrap(), SearchResultEnum::Character(_)));
+}
+
+#[tokio::test]
+async fn should_retrieve_meta_offline() {
+    let (jikan, _) = jikan(&[
+        ("/meta/status", include_str!("fixtures/meta_status.json")),
+        ("/meta/requests/anime/monthly/1", include_str!("fixtures/meta_requests.json")),
+    ]);
+    let status = jikan.retrieve_api_status().await.unwrap();
+    assert_eq!(status.requests_this_month, 6122354);
+    let endpoints = jikan.retrieve_request_info(InfoAbout::Anime, Period::Monthly, 1).await.unwrap();
+    assert_eq!(endpoints.len(), 2);
+}
+
+#[tokio::test]
+async fn should_report_unrouted_requests_as_not_found_offline() {
+    let (jikan, _) = jikan(&[]);
+    let err = jikan.find_anime(1).await.unwrap_err();
+    assert!(err.is_not_found());
+}
+
+#[tokio::test]
+async fn should_expose_response_meta_offline() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1", include_str!("fixtures/anime.json")),
+        ("/anime/1/stats", include_str!("fixtures/anime_stats.json")),
+        ("/search/anime?page=1&q=one", include_str!("fixtures/search_anime.json")),
+    ]);
+    let meta = jikan.find_anime(1).await.unwrap().meta();
+    assert_eq!(meta.request_hash, "request:anime:7ee3a3f2cda3f1ac8e0bd8ef7a5a2dc1dd1bb7e6");
+    assert!(meta.request_cached);
+    assert_eq!(meta.request_cache_expiry, 43200);
+    assert_eq!(meta.status, 200);
+    assert!(!meta.from_local_cache);
+
+    let meta = jikan.find_stats(SourceType::Anime(AnimeId(1))).await.unwrap().meta();
+    assert!(!meta.request_cached);
+    assert_eq!(meta.status, 200);
+
+    let query = SearchQueryBuilder::new(SearchSource::Anime).name("one");
+    let meta = jikan.search(query).await.unwrap().meta();
+    assert_eq!(meta.request_cache_expiry, 432000);
+}
+
+#[tokio::test]
+async fn should_find_random_entries_offline() {
+    let (jikan, fixtures) = jikan(&[
+        ("/v4/random/anime", include_str!("fixtures/v4/random_anime.json")),
+        ("/v4/random/users", include_str!("fixtures/v4/random_user.json")),
+        ("/v3/anime/1", include_str!("fixtures/anime.json")),
+        ("/v3/anime/1/videos", include_str!("fixtures/videos.json")),
+        ("/v3/user/Bruno319/profile", include_str!("fixtures/user_profile.json")),
+    ]);
+    let anime = jikan.random_anime().await.unwrap();
+    assert_eq!(anime.title, "Cowboy Bebop");
+    assert_eq!(anime.get_videos().await.unwrap().promo.len(), 1);
+    let profile = jikan.random_user().await.unwrap();
+    assert_eq!(profile.username, "Bruno319");
+    assert_eq!(requested_urls(&fixtures), vec![
+        "https://api.jikan.moe/v4/random/anime",
+        "http://jikan.test/v3/anime/1",
+        "http://jikan.test/v3/anime/1/videos",
+        "https://api.jikan.moe/v4/random/users",
+        "http://jikan.test/v3/user/Bruno319/profile",
+    ]);
+}
@@ -0,0 +1,140 @@
+extern crate jikan_rs;
+
+mod common;
+
+use futures::StreamExt;
+
+use jikan_rs::Error;
+use jikan_rs::prelude::*;
+use jikan_rs::transport::Response;
+
+use common::{jikan, requested_urls};
+
+const ANIME_REVIEWS: &str = include_str!("fixtures/anime_reviews.json");
+const SEARCH_ANIME: &str = include_str!("fixtures/search_anime.json");
+const EMPTY_REVIEWS: &str = r#"{"request_hash": "request:anime:empty", "request_cached": false, "request_cache_expiry": 43200, "reviews": []}"#;
+
+#[tokio::test]
+async fn should_stop_on_empty_page() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/anime/1/reviews/1", Response::new(200, ANIME_REVIEWS));
+    fixtures.route("/anime/1/reviews/2", Response::new(200, ANIME_REVIEWS));
+    fixtures.route("/anime/1/reviews/3", Response::new(200, EMPTY_REVIEWS));
+
+    let reviews: Vec<_> = jikan.stream_reviews::<AnimeReviewer>(SourceType::Anime(AnimeId(1)), 1)
+        .collect()
+        .await;
+
+    assert_eq!(reviews.len(), 2);
+    assert!(reviews.iter().all(Result::is_ok));
+    assert_eq!(fixtures.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn should_stop_when_next_page_does_not_exist() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/anime/1/reviews/1", Response::new(200, ANIME_REVIEWS));
+
+    let pages: Vec<_> = jikan.stream_reviews_pages::<AnimeReviewer>(SourceType::Anime(AnimeId(1)), 1)
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].as_ref().unwrap().number, 1);
+    assert_eq!(fixtures.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_fetch_pages_lazily() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/anime/1/userupdates/1", Response::new(200, include_str!("fixtures/anime_userupdates.json")));
+    fixtures.route("/anime/1/userupdates/2", Response::new(200, include_str!("fixtures/anime_userupdates.json")));
+
+    let updates: Vec<_> = jikan.stream_user_updates::<AnimeUserUpdate>(SourceType::Anime(AnimeId(1)), 1)
+        .take(2)
+        .collect()
+        .await;
+
+    assert_eq!(updates.len(), 2);
+    assert_eq!(requested_urls(&fixtures), vec!["http://jikan.test/v3/anime/1/userupdates/1"]);
+}
+
+#[tokio::test]
+async fn should_resume_from_page() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/club/1/members/3", Response::new(200, include_str!("fixtures/club_members.json")));
+
+    let pages: Vec<_> = jikan.stream_club_members_pages(1, 3)
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 1);
+    assert_eq!(pages[0].as_ref().unwrap().number, 3);
+    assert_eq!(requested_urls(&fixtures), vec![
+        "http://jikan.test/v3/club/1/members/3",
+        "http://jikan.test/v3/club/1/members/4",
+    ]);
+}
+
+#[tokio::test]
+async fn should_stop_on_last_page() {
+    let (jikan, fixtures) = jikan(&[]);
+    let last_page = SEARCH_ANIME.replace("\"last_page\": 20", "\"last_page\": 2");
+    fixtures.route("/search/anime?page=1&q=one", Response::new(200, &last_page));
+    fixtures.route("/search/anime?page=2&q=one", Response::new(200, &last_page));
+    fixtures.route("/search/anime?page=3&q=one", Response::new(200, &last_page));
+
+    let query = SearchQueryBuilder::new(SearchSource::Anime).name("one");
+    let pages: Vec<_> = jikan.stream_search_pages::<AnimeResult>(query)
+        .collect()
+        .await;
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[1].as_ref().unwrap().last_page, Some(2));
+    assert_eq!(fixtures.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_stream_top_from_given_page() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/top/anime/2/airing", Response::new(200, include_str!("fixtures/top_anime.json")));
+
+    let top: Vec<_> = jikan.stream_top::<RankedAnime>(Top::Anime { page: 2, subtype: TopAnimeSubtype::Airing })
+        .collect()
+        .await;
+
+    assert_eq!(top.len(), 1);
+    assert_eq!(requested_urls(&fixtures)[1], "http://jikan.test/v3/top/anime/3/airing");
+}
+
+#[tokio::test]
+async fn should_stream_genres_producers_and_magazines() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/genre/anime/2/1", Response::new(200, include_str!("fixtures/genre_anime.json")));
+    fixtures.route("/genre/manga/25/1", Response::new(200, include_str!("fixtures/genre_manga.json")));
+    fixtures.route("/producer/1/1", Response::new(200, include_str!("fixtures/producer.json")));
+    fixtures.route("/magazine/1/1", Response::new(200, include_str!("fixtures/magazine.json")));
+
+    assert_eq!(jikan.stream_animes_with_genre(AnimeGenre::Adventure, 1).count().await, 1);
+    assert_eq!(jikan.stream_mangas_with_genre(MangaGenre::Shoujo, 1).count().await, 1);
+    assert_eq!(jikan.stream_producer(1, 1).count().await, 1);
+    assert_eq!(jikan.stream_magazine(1, 1).count().await, 1);
+}
+
+#[tokio::test]
+async fn should_yield_error_and_stop() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/1/reviews/1", Response::new(200, include_str!("fixtures/manga_reviews.json")));
+
+    let missing: Vec<_> = jikan.stream_reviews::<AnimeReviewer>(SourceType::Anime(AnimeId(1)), 1)
+        .collect()
+        .await;
+    assert_eq!(missing.len(), 1);
+    assert!(missing[0].as_ref().unwrap_err().is_not_found());
+
+    let mismatched: Vec<_> = jikan.stream_reviews::<AnimeReviewer>(SourceType::Manga(MangaId(1)), 1)
+        .collect()
+        .await;
+    assert_eq!(mismatched.len(), 1);
+    assert!(matches!(mismatched[0], Err(Error::UnexpectedResult { expected: "Anime Reviews", returned: "Manga Reviews" })));
+}
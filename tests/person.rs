@@ -3,16 +3,14 @@ extern crate jikan_rs;
 extern crate lazy_static;
 
 use jikan_rs::client::Jikan;
-use std::thread;
-use std::time::Duration;
+use jikan_rs::RateLimiter;
 
 lazy_static! {
-    static ref JIKAN: Jikan = Jikan::new();
+    static ref JIKAN: Jikan = Jikan::new().with_rate_limiter(RateLimiter::default());
 }
 
 #[tokio::test]
 async fn should_get_person_pictures() {
-    thread::sleep(Duration::from_secs(3));
     let pictures = JIKAN.find_person(1)
         .await.unwrap()
         .get_pictures()
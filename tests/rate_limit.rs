@@ -0,0 +1,25 @@
+extern crate jikan_rs;
+
+use std::time::{Duration, Instant};
+
+use jikan_rs::RateLimiter;
+
+#[tokio::test]
+async fn should_not_wait_while_quota_is_available() {
+    let rate_limiter = RateLimiter::new(3, 30);
+    let start = Instant::now();
+    for _ in 0..3 {
+        rate_limiter.acquire().await;
+    }
+    assert!(start.elapsed() < Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn should_wait_when_per_second_quota_is_exhausted() {
+    let rate_limiter = RateLimiter::new(2, 30);
+    let start = Instant::now();
+    for _ in 0..3 {
+        rate_limiter.acquire().await;
+    }
+    assert!(start.elapsed() >= Duration::from_millis(450));
+}
@@ -0,0 +1,50 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::prelude::*;
+
+async fn find_anime(body: &str) -> Anime {
+    let (jikan, _) = common::jikan(&[("/anime/1", body)]);
+    jikan.find_anime(1).await.unwrap()
+}
+
+#[tokio::test]
+async fn should_list_relations_in_order() {
+    let anime = find_anime(include_str!("fixtures/anime.json")).await;
+    let kinds: Vec<&RelationKind> = anime.related.relations().iter().map(|relation| &relation.relation).collect();
+    assert_eq!(kinds, vec![&RelationKind::Adaptation, &RelationKind::SideStory, &RelationKind::Summary]);
+    assert_eq!(anime.related.iter().count(), 5);
+    assert_eq!(anime.related.of_kind(RelationKind::SideStory).collect::<Vec<_>>(),
+               vec![EntityRef::Anime(AnimeId(5)), EntityRef::Anime(AnimeId(17205))]);
+    assert_eq!(anime.related.of_kind(RelationKind::Adaptation).next(), Some(EntityRef::Manga(MangaId(173))));
+    assert_eq!(anime.related.of_kind(RelationKind::Prequel).count(), 0);
+    assert_eq!(anime.genres[0].content_type, ContentType::Anime);
+}
+
+#[tokio::test]
+async fn should_preserve_unknown_relations() {
+    let body = include_str!("fixtures/anime.json").replace("\"Summary\": [", "\"Crossover\": [");
+    let anime = find_anime(&body).await;
+    let (kind, entity) = anime.related.entities().last().unwrap();
+    assert_eq!(kind, &RelationKind::Unknown("Crossover".to_string()));
+    assert_eq!(entity, EntityRef::Anime(AnimeId(4037)));
+
+    let json = serde_json::to_string(&anime.related).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["Crossover"][0]["mal_id"], 4037);
+    assert_eq!(value["Side story"][1]["type"], "anime");
+    let related: RelatedContent = serde_json::from_str(&json).unwrap();
+    assert_eq!(related, anime.related);
+}
+
+#[tokio::test]
+async fn should_accept_empty_relations() {
+    let body = include_str!("fixtures/anime.json");
+    let start = body.find("\"related\": {").unwrap();
+    let end = start + body[start..].find("\n  },").unwrap() + 4;
+    let body = format!("{}\"related\": []{}", &body[..start], &body[end..]);
+    let anime = find_anime(&body).await;
+    assert!(anime.related.is_empty());
+    assert_eq!(anime.related.entities().count(), 0);
+}
@@ -0,0 +1,97 @@
+extern crate jikan_rs;
+
+mod common;
+
+use std::sync::Arc;
+
+use jikan_rs::client::Jikan;
+use jikan_rs::Error;
+use jikan_rs::prelude::*;
+use jikan_rs::transport::FixtureTransport;
+
+fn jikan() -> (Jikan, Arc<FixtureTransport>) {
+    common::jikan(&[
+        ("/anime/1", include_str!("fixtures/anime.json")),
+        ("/producer/14/1", include_str!("fixtures/producer.json")),
+        ("/producer/23/1", include_str!("fixtures/producer.json")),
+        ("/person/1", include_str!("fixtures/person.json")),
+        ("/character/1", include_str!("fixtures/character.json")),
+    ])
+}
+
+#[test]
+fn should_parse_references_from_urls() {
+    assert_eq!(Reference::from_url("https://myanimelist.net/anime/producer/14/Sunrise"),
+               Some(Reference::Producer(ProducerId(14))));
+    assert_eq!(Reference::from_url("https://myanimelist.net/manga/magazine/1/Big_Comic_Original"),
+               Some(Reference::Magazine(MagazineId(1))));
+    assert_eq!(Reference::from_url("https://myanimelist.net/anime/1/Cowboy_Bebop"), Some(Reference::Anime(AnimeId(1))));
+    assert_eq!(Reference::from_url("https://myanimelist.net/manga/173/Cowboy_Bebop"), Some(Reference::Manga(MangaId(173))));
+    assert_eq!(Reference::from_url("https://myanimelist.net/people/1/Tomokazu_Seki"), Some(Reference::Person(PersonId(1))));
+    assert_eq!(Reference::from_url("https://myanimelist.net/character/1/Spike_Spiegel"),
+               Some(Reference::Character(CharacterId(1))));
+    assert_eq!(Reference::from_url("https://myanimelist.net/anime/genre/1/Action"), None);
+    assert_eq!(Reference::from_url("https://myanimelist.net/profile/Nekomata1037"), None);
+}
+
+#[tokio::test]
+async fn should_resolve_items_by_their_context() {
+    let (jikan, _) = jikan();
+    let anime = jikan.find_anime(1).await.unwrap();
+    match anime.studios[0].resolve(&jikan).await.unwrap() {
+        Entity::Producer(producer) => assert_eq!(producer.data.name, "Studio Pierrot"),
+        entity => panic!("expected a producer, got {:?}", entity),
+    }
+    match anime.genres[0].resolve(&jikan).await {
+        Err(Error::Unresolvable(url)) => assert_eq!(url, "https://myanimelist.net/anime/genre/1/Action"),
+        result => panic!("expected an unresolvable reference, got {:?}", result),
+    }
+
+    let character = jikan.find_character(1).await.unwrap();
+    match character.animeography[0].resolve(&jikan).await.unwrap() {
+        Entity::Anime(anime) => assert_eq!(anime.mal_id, 1),
+        entity => panic!("expected an anime, got {:?}", entity),
+    }
+    let person = jikan.find_person(1).await.unwrap();
+    assert_eq!(person.voice_acting_roles[0].character.reference(), Some(Reference::Character(CharacterId(5))));
+}
+
+#[tokio::test]
+async fn should_resolve_each_reference_once() {
+    let (jikan, fixtures) = jikan();
+    let anime = jikan.find_anime(1).await.unwrap();
+    let references = vec![
+        anime.studios[0].reference().unwrap(),
+        anime.producers[0].reference().unwrap(),
+        Reference::Person(PersonId(1)),
+        anime.studios[0].reference().unwrap(),
+        Reference::Person(PersonId(1)),
+    ];
+    let entities = jikan.resolve_all(references).await;
+
+    let resolved: Vec<Reference> = entities.iter().map(|(reference, _)| *reference).collect();
+    assert_eq!(resolved, vec![Reference::Producer(ProducerId(14)), Reference::Producer(ProducerId(23)),
+                              Reference::Person(PersonId(1))]);
+    assert!(matches!(entities[2].1, Ok(Entity::Person(_))));
+    assert_eq!(fixtures.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn should_keep_resolving_after_a_missing_reference() {
+    let (jikan, fixtures) = jikan();
+    let references = vec![
+        Reference::Producer(ProducerId(14)),
+        Reference::Anime(AnimeId(404)),
+        Reference::Character(CharacterId(1)),
+    ];
+    let entities = jikan.resolve_all(references).await;
+
+    assert_eq!(entities.len(), 3);
+    assert!(matches!(entities[0], (Reference::Producer(ProducerId(14)), Ok(Entity::Producer(_)))));
+    match &entities[1] {
+        (Reference::Anime(AnimeId(404)), Err(err)) => assert!(err.is_not_found()),
+        entity => panic!("expected a missing anime, got {:?}", entity),
+    }
+    assert!(matches!(entities[2], (Reference::Character(CharacterId(1)), Ok(Entity::Character(_)))));
+    assert_eq!(fixtures.requests().len(), 3);
+}
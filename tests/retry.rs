@@ -0,0 +1,95 @@
+extern crate jikan_rs;
+
+mod common;
+
+use std::time::{Duration, Instant};
+
+use jikan_rs::RetryPolicy;
+use jikan_rs::prelude::{MangaId, SourceType};
+use jikan_rs::transport::Response;
+
+use common::jikan;
+
+const MORE_INFO: &str = r#"{"request_hash":"h","request_cached":false,"request_cache_expiry":0,"moreinfo":null}"#;
+
+#[tokio::test]
+async fn should_retry_transient_failures() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/1/moreinfo", Response::new(503, ""));
+    fixtures.route("/manga/1/moreinfo", Response::new(429, ""));
+    fixtures.route("/manga/1/moreinfo", Response::new(200, MORE_INFO));
+    let jikan = jikan.with_retry_policy(RetryPolicy::new().base_delay(Duration::from_millis(10)));
+
+    let more_info = jikan.find_more_info(SourceType::Manga(MangaId(1))).await.unwrap();
+
+    assert!(more_info.is_none());
+    assert_eq!(fixtures.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn should_not_retry_other_statuses() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/1/moreinfo", Response::new(404, ""));
+    fixtures.route("/manga/1/moreinfo", Response::new(200, MORE_INFO));
+    let jikan = jikan.with_retry_policy(RetryPolicy::new().base_delay(Duration::from_millis(10)));
+
+    let error = jikan.find_more_info(SourceType::Manga(MangaId(1))).await.unwrap_err();
+
+    assert!(error.is_not_found());
+    assert_eq!(fixtures.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn should_give_up_after_max_attempts() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/1/moreinfo", Response::new(500, ""));
+    let jikan = jikan.with_retry_policy(RetryPolicy::new().max_attempts(2).base_delay(Duration::from_millis(10)));
+
+    let error = jikan.find_more_info(SourceType::Manga(MangaId(1))).await.unwrap_err();
+
+    assert_eq!(error.status(), Some(500));
+    assert_eq!(fixtures.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_cap_retry_after_with_max_delay() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/1/moreinfo", Response::new(429, "").with_header("Retry-After", "86400"));
+    fixtures.route("/manga/1/moreinfo", Response::new(200, MORE_INFO));
+    let jikan = jikan.with_retry_policy(RetryPolicy::new().max_delay(Duration::from_millis(50)));
+    let start = Instant::now();
+
+    jikan.find_more_info(SourceType::Manga(MangaId(1))).await.unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(fixtures.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_honor_retry_after_dates() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/1/moreinfo", Response::new(503, "").with_header("Retry-After", "Wed, 21 Oct 2015 07:28:00 GMT"));
+    fixtures.route("/manga/1/moreinfo", Response::new(200, MORE_INFO));
+    let jikan = jikan.with_retry_policy(RetryPolicy::new().base_delay(Duration::from_secs(20)).max_delay(Duration::from_secs(60)));
+    let start = Instant::now();
+
+    jikan.find_more_info(SourceType::Manga(MangaId(1))).await.unwrap();
+
+    // The date is past, so the request is sent again without waiting for the 10 to 20 seconds of backoff
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(fixtures.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn should_back_off_on_unreadable_retry_after() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/1/moreinfo", Response::new(503, "").with_header("Retry-After", "later"));
+    fixtures.route("/manga/1/moreinfo", Response::new(200, MORE_INFO));
+    let jikan = jikan.with_retry_policy(RetryPolicy::new().base_delay(Duration::from_millis(300)).jitter(false));
+    let start = Instant::now();
+
+    jikan.find_more_info(SourceType::Manga(MangaId(1))).await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(fixtures.requests().len(), 2);
+}
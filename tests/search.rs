@@ -4,16 +4,14 @@ extern crate lazy_static;
 
 use jikan_rs::client::Jikan;
 use jikan_rs::prelude::*;
-use std::thread;
-use std::time::Duration;
+use jikan_rs::RateLimiter;
 
 lazy_static! {
-    static ref JIKAN: Jikan = Jikan::new();
+    static ref JIKAN: Jikan = Jikan::new().with_rate_limiter(RateLimiter::default());
 }
 
 #[tokio::test]
 async fn should_search_for_anime_one_piece() {
-    thread::sleep(Duration::from_secs(3));
     let query = SearchQueryBuilder::new(SearchSource::Anime)
         .name("one p")
         .type_source(SearchSourceType::Anime(AnimeType::TV))
@@ -32,7 +30,6 @@ async fn should_search_for_anime_one_piece() {
 
 #[tokio::test]
 async fn should_search_for_manga_berserk() {
-    thread::sleep(Duration::from_secs(3));
     let query = SearchQueryBuilder::new(SearchSource::Manga)
         .name("berser")
         .type_source(SearchSourceType::Manga(MangaType::Manga));
@@ -50,7 +47,6 @@ async fn should_search_for_manga_berserk() {
 
 #[tokio::test]
 async fn should_search_for_person_masashi_kishimoto() {
-    thread::sleep(Duration::from_secs(3));
     let query = SearchQueryBuilder::new(SearchSource::Person)
         .name("岸本 斉史");
 
@@ -67,7 +63,6 @@ async fn should_search_for_person_masashi_kishimoto() {
 
 #[tokio::test]
 async fn should_search_for_character_lelouch_lamperouge() {
-    thread::sleep(Duration::from_secs(3));
     let query = SearchQueryBuilder::new(SearchSource::Character)
         .name("ルルーシュ");
 
@@ -0,0 +1,145 @@
+extern crate jikan_rs;
+
+mod common;
+
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use jikan_rs::prelude::*;
+
+use common::jikan;
+
+const EMPTY_SEARCH: &str = r#"{"request_hash":"h","request_cached":false,"request_cache_expiry":0,"results":[],"last_page":1}"#;
+
+/// Serializes `value`, reads it back and checks nothing was lost on the way.
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Value {
+    let json = serde_json::to_value(value).unwrap();
+    let parsed: T = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+    json
+}
+
+#[tokio::test]
+async fn should_serialize_entities_with_jikan_shape() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1", include_str!("fixtures/anime.json")),
+        ("/manga/1", include_str!("fixtures/manga.json")),
+        ("/person/1", include_str!("fixtures/person.json")),
+        ("/character/1", include_str!("fixtures/character.json")),
+    ]);
+    let anime = round_trip(&jikan.find_anime(1).await.unwrap());
+    let fixture: Value = serde_json::from_str(include_str!("fixtures/anime.json")).unwrap();
+    for key in &["mal_id", "request_hash", "request_cache_expiry", "type", "premiered", "opening_themes"] {
+        assert_eq!(anime[key], fixture[key], "{}", key);
+    }
+    assert!(anime.get("client").is_none());
+    assert!(anime.get("http_meta").is_none());
+
+    round_trip(&jikan.find_manga(1).await.unwrap());
+    round_trip(&jikan.find_person(1).await.unwrap());
+    round_trip(&jikan.find_character(1).await.unwrap());
+}
+
+#[tokio::test]
+async fn should_serialize_result_enums_tagged_by_variant() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1/stats", include_str!("fixtures/anime_stats.json")),
+        ("/search/anime?page=1&q=bebop", include_str!("fixtures/search_anime.json")),
+    ]);
+    let stats = round_trip(&jikan.find_stats(SourceType::Anime(AnimeId(1))).await.unwrap());
+    assert_eq!(stats["Anime"]["total"], 1080959);
+    let search = jikan.search(SearchQueryBuilder::new(SearchSource::Anime).name("bebop")).await.unwrap();
+    assert!(round_trip(&search)["Anime"]["results"].is_array());
+}
+
+/// Writes `value` as a JSON string and checks it parses back to the same variant and content.
+fn assert_string_round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value, "{}", json);
+}
+
+#[tokio::test]
+async fn should_round_trip_every_result_enum_variant() {
+    let (jikan, _) = jikan(&[
+        ("/anime/1/stats", include_str!("fixtures/anime_stats.json")),
+        ("/manga/1/stats", include_str!("fixtures/manga_stats.json")),
+        ("/anime/1/reviews/1", include_str!("fixtures/anime_reviews.json")),
+        ("/manga/1/reviews/1", include_str!("fixtures/manga_reviews.json")),
+        ("/anime/1/userupdates/1", include_str!("fixtures/anime_userupdates.json")),
+        ("/manga/1/userupdates/1", include_str!("fixtures/manga_userupdates.json")),
+        ("/top/anime/1/", include_str!("fixtures/top_anime.json")),
+        ("/top/manga/1/", include_str!("fixtures/top_manga.json")),
+        ("/top/characters/1", include_str!("fixtures/top_characters.json")),
+        ("/top/people/1", include_str!("fixtures/top_people.json")),
+        ("/user/Bruno319/profile", include_str!("fixtures/user_profile.json")),
+        ("/user/Bruno319/history/", include_str!("fixtures/user_history.json")),
+        ("/user/Bruno319/friends/1", include_str!("fixtures/user_friends.json")),
+        ("/user/Bruno319/animelist?page=1&sort=descending", include_str!("fixtures/user_animelist.json")),
+        ("/user/Bruno319/mangalist?page=1&sort=descending", include_str!("fixtures/user_mangalist.json")),
+        ("/search/anime?page=1&q=bebop", include_str!("fixtures/search_anime.json")),
+        ("/search/manga?page=1&q=bebop", EMPTY_SEARCH),
+        ("/search/person?page=1&q=bebop", EMPTY_SEARCH),
+        ("/search/character?page=1&q=bebop", include_str!("fixtures/search_character.json")),
+    ]);
+
+    assert_string_round_trip(jikan.find_stats(SourceType::Anime(AnimeId(1))).await.unwrap());
+    assert_string_round_trip(jikan.find_stats(SourceType::Manga(MangaId(1))).await.unwrap());
+
+    assert_string_round_trip(jikan.find_reviews(SourceType::Anime(AnimeId(1)), 1).await.unwrap());
+    assert_string_round_trip(jikan.find_reviews(SourceType::Manga(MangaId(1)), 1).await.unwrap());
+    assert_string_round_trip(Reviews::Anime(vec![]));
+    assert_string_round_trip(Reviews::Manga(vec![]));
+
+    assert_string_round_trip(jikan.find_user_updates(SourceType::Anime(AnimeId(1)), 1).await.unwrap());
+    assert_string_round_trip(jikan.find_user_updates(SourceType::Manga(MangaId(1)), 1).await.unwrap());
+    assert_string_round_trip(UserUpdates::Anime(vec![]));
+    assert_string_round_trip(UserUpdates::Manga(vec![]));
+
+    assert_string_round_trip(jikan.find_top(Top::Anime { page: 1, subtype: TopAnimeSubtype::All }).await.unwrap());
+    assert_string_round_trip(jikan.find_top(Top::Manga { page: 1, subtype: TopMangaSubtype::All }).await.unwrap());
+    assert_string_round_trip(jikan.find_top(Top::Character(1)).await.unwrap());
+    assert_string_round_trip(jikan.find_top(Top::People(1)).await.unwrap());
+    assert_string_round_trip(TopResult::Anime(vec![]));
+    assert_string_round_trip(TopResult::Manga(vec![]));
+    assert_string_round_trip(TopResult::Character(vec![]));
+    assert_string_round_trip(TopResult::People(vec![]));
+
+    assert_string_round_trip(jikan.find_user("Bruno319", UserInfo::Profile).await.unwrap());
+    assert_string_round_trip(jikan.find_user("Bruno319", UserInfo::History { source: HistorySource::Both }).await.unwrap());
+    assert_string_round_trip(jikan.find_user("Bruno319", UserInfo::Friends { page: 1 }).await.unwrap());
+    assert_string_round_trip(jikan.find_user("Bruno319", UserInfo::Animelist { query: AnimeListQuery::new() }).await.unwrap());
+    assert_string_round_trip(jikan.find_user("Bruno319", UserInfo::Mangalist { query: MangaListQuery::new() }).await.unwrap());
+    assert_string_round_trip(UserResultEnum::History(vec![]));
+    assert_string_round_trip(UserResultEnum::Friends(vec![]));
+    assert_string_round_trip(UserResultEnum::AnimeList(vec![]));
+    assert_string_round_trip(UserResultEnum::MangaList(vec![]));
+
+    for source in [SearchSource::Anime, SearchSource::Manga, SearchSource::Person, SearchSource::Character] {
+        assert_string_round_trip(jikan.search(SearchQueryBuilder::new(source).name("bebop")).await.unwrap());
+    }
+}
+
+#[tokio::test]
+async fn should_serialize_list_statuses_as_jikan_codes() {
+    let anime_list = include_str!("fixtures/user_animelist.json")
+        .replace("\"watching_status\": 2", "\"watching_status\": 6")
+        .replace("\"airing_status\": 2", "\"airing_status\": 3");
+    let manga_list = include_str!("fixtures/user_mangalist.json").replace("\"reading_status\": 1", "\"reading_status\": 3");
+    let (jikan, _) = jikan(&[
+        ("/user/Bruno319/animelist?page=1&sort=descending", &anime_list),
+        ("/user/Bruno319/mangalist?page=1&sort=descending", &manga_list),
+    ]);
+    let anime_list = jikan.find_user("Bruno319", UserInfo::Animelist { query: AnimeListQuery::new() }).await.unwrap();
+    let json = round_trip(&anime_list);
+    assert_eq!(json["AnimeList"][0]["watching_status"], 6);
+    assert_eq!(json["AnimeList"][0]["airing_status"], 3);
+
+    let manga_list = jikan.find_user("Bruno319", UserInfo::Mangalist { query: MangaListQuery::new() }).await.unwrap();
+    let json = round_trip(&manga_list);
+    assert_eq!(json["MangaList"][0]["reading_status"], 3);
+    assert_eq!(json["MangaList"][0]["publishing_status"], 2);
+    assert_string_round_trip(manga_list);
+}
@@ -0,0 +1,55 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::Error;
+use jikan_rs::transport::Response;
+
+use common::jikan;
+
+const NOT_FOUND: &str = r#"{"status":404,"type":"BadResponseException","message":"Resource does not exist","error":"404 on https://myanimelist.net/anime/2/"}"#;
+const BAD_REQUEST: &str = r#"{"status":400,"type":"ValidationException","message":"Invalid or incomplete request. Please double check the request documentation","error":null}"#;
+
+#[tokio::test]
+async fn should_return_not_found_with_the_jikan_error() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/anime/2", Response::new(404, NOT_FOUND));
+
+    let error = jikan.find_anime(2).await.unwrap_err();
+
+    assert!(error.is_not_found());
+    assert_eq!(error.status(), Some(404));
+    let api_error = error.api_error().unwrap();
+    assert_eq!(api_error.error_type, "BadResponseException");
+    assert_eq!(api_error.error.as_deref(), Some("404 on https://myanimelist.net/anime/2/"));
+    assert_eq!(error.to_string(), "Resource does not exist: Resource does not exist");
+}
+
+#[tokio::test]
+async fn should_return_bad_request_with_the_jikan_error() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/manga/0", Response::new(400, BAD_REQUEST));
+
+    let error = jikan.find_manga(0).await.unwrap_err();
+
+    match &error {
+        Error::BadRequest { error: Some(api_error) } => assert_eq!(api_error.status, 400),
+        error => panic!("Unexpected error: {:?}", error),
+    }
+    assert_eq!(error.status(), Some(400));
+    assert!(!error.is_not_found());
+}
+
+#[tokio::test]
+async fn should_return_upstream_error_without_a_jikan_error() {
+    let (jikan, fixtures) = jikan(&[]);
+    fixtures.route("/person/1", Response::new(503, "<html>Service Unavailable</html>"));
+
+    let error = jikan.find_person(1).await.unwrap_err();
+
+    match &error {
+        Error::Upstream { status: 503, error: None } => (),
+        error => panic!("Unexpected error: {:?}", error),
+    }
+    assert_eq!(error.to_string(), "Jikan failed to serve the request with status 503");
+}
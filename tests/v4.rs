@@ -0,0 +1,95 @@
+extern crate jikan_rs;
+
+mod common;
+
+use jikan_rs::prelude::*;
+use jikan_rs::v4::SearchQuery;
+
+use common::{jikan_v4 as jikan, requested_urls};
+
+#[tokio::test]
+async fn should_find_an_anime_with_v4() {
+    let (jikan, fixtures) = jikan(&[("/anime/1", include_str!("fixtures/v4/anime.json"))]);
+    let anime = jikan.v4().find_anime(1).await.unwrap();
+    assert_eq!(anime.title, "Cowboy Bebop");
+    assert_eq!(anime.year, Some(1998));
+    assert_eq!(anime.aired.prop.unwrap().from.year, Some(1998));
+    assert_eq!(anime.studios[0].name, "Sunrise");
+    assert_eq!(anime.broadcast.timezone.as_deref(), Some("Asia/Tokyo"));
+    assert_eq!(requested_urls(&fixtures), vec!["http://jikan.test/v4/anime/1"]);
+}
+
+#[tokio::test]
+async fn should_find_a_season_with_v4() {
+    let (jikan, fixtures) = jikan(&[("/seasons/2019/summer?page=1", include_str!("fixtures/v4/season.json"))]);
+    let season = jikan.v4().find_season(Season::Summer(2019), 1).await.unwrap();
+    assert_eq!(season.data[0].title, "Dr. Stone");
+    assert_eq!(season.pagination.last_visible_page, 3);
+    assert!(season.pagination.has_next_page);
+    assert_eq!(season.pagination.items.unwrap().total, 51);
+    assert_eq!(requested_urls(&fixtures), vec!["http://jikan.test/v4/seasons/2019/summer?page=1"]);
+}
+
+#[tokio::test]
+async fn should_map_later_season_to_upcoming_with_v4() {
+    let (jikan, fixtures) = jikan(&[("/seasons/upcoming?page=1", include_str!("fixtures/v4/season.json"))]);
+    jikan.v4().find_season(Season::Later, 1).await.unwrap();
+    assert_eq!(requested_urls(&fixtures), vec!["http://jikan.test/v4/seasons/upcoming?page=1"]);
+}
+
+#[tokio::test]
+async fn should_find_season_archives_with_v4() {
+    let (jikan, _) = jikan(&[("/seasons", include_str!("fixtures/v4/season_archive.json"))]);
+    let archives = jikan.v4().find_season_archives().await.unwrap();
+    assert_eq!(archives[0].year, 2020);
+    assert_eq!(archives[0].seasons.len(), 4);
+}
+
+#[tokio::test]
+async fn should_filter_schedules_with_v4() {
+    let (jikan, fixtures) = jikan(&[
+        ("/schedules?filter=monday&page=1", include_str!("fixtures/v4/season.json")),
+        ("/schedules?page=1", include_str!("fixtures/v4/season.json")),
+    ]);
+    jikan.v4().find_schedule(ScheduleOn::Monday, 1).await.unwrap();
+    jikan.v4().find_schedule(ScheduleOn::Week, 1).await.unwrap();
+    assert_eq!(requested_urls(&fixtures), vec![
+        "http://jikan.test/v4/schedules?filter=monday&page=1",
+        "http://jikan.test/v4/schedules?page=1",
+    ]);
+}
+
+#[tokio::test]
+async fn should_search_mangas_with_v4() {
+    let (jikan, fixtures) = jikan(&[("/manga?page=2&limit=1&q=monster&order_by=score", include_str!("fixtures/v4/search_manga.json"))]);
+    let query = SearchQuery::new()
+        .query("monster".to_string())
+        .page(2)
+        .limit(1)
+        .order_by("score".to_string());
+    let result = jikan.v4().search_manga(&query).await.unwrap();
+    assert_eq!(result.data[0].title, "Monster");
+    assert_eq!(result.data[0].authors[0].name, "Urasawa, Naoki");
+    assert_eq!(result.pagination.current_page, Some(2));
+    assert_eq!(requested_urls(&fixtures), vec!["http://jikan.test/v4/manga?page=2&limit=1&q=monster&order_by=score"]);
+}
+
+#[tokio::test]
+async fn should_find_genres_and_users_with_v4() {
+    let (jikan, fixtures) = jikan(&[
+        ("/genres/anime", include_str!("fixtures/v4/genres_anime.json")),
+        ("/users/Xinil", include_str!("fixtures/v4/user.json")),
+    ]);
+    let genres = jikan.v4().find_anime_genres().await.unwrap();
+    assert_eq!(genres[1].name, "Adventure");
+    let user = jikan.v4().find_user("Xinil").await.unwrap();
+    assert_eq!(user.location.as_deref(), Some("California"));
+    assert_eq!(requested_urls(&fixtures), vec!["http://jikan.test/v4/genres/anime", "http://jikan.test/v4/users/Xinil"]);
+}
+
+#[tokio::test]
+async fn should_share_v3_error_handling_with_v4() {
+    let (jikan, _) = jikan(&[]);
+    let err = jikan.v4().find_character(1).await.unwrap_err();
+    assert!(err.is_not_found());
+}
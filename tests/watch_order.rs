@@ -0,0 +1,71 @@
+extern crate jikan_rs;
+
+mod common;
+
+use std::sync::Arc;
+
+use jikan_rs::client::Jikan;
+use jikan_rs::prelude::*;
+use jikan_rs::transport::FixtureTransport;
+
+use common::{related, with_related};
+
+fn anime(title: &str, anime_type: &str, aired_from: &str, related: &str) -> String {
+    with_related(include_str!("fixtures/anime.json"), title, related)
+        .replacen("\"type\": \"TV\"", &format!("\"type\": \"{}\"", anime_type), 1)
+        .replacen("\"from\": \"1998-04-03T00:00:00+00:00\"", &format!("\"from\": \"{}T00:00:00+00:00\"", aired_from), 1)
+}
+
+fn jikan() -> (Jikan, Arc<FixtureTransport>) {
+    let routes = [
+        ("/anime/1", anime("Season 1", "TV", "2001-01-01", &related(&[
+            ("Prequel", "anime", 6), ("Sequel", "anime", 2), ("Summary", "anime", 3),
+            ("Side story", "anime", 4), ("Adaptation", "manga", 7),
+        ]))),
+        ("/anime/2", anime("Season 2", "TV", "2003-01-01", &related(&[("Prequel", "anime", 1), ("Sequel", "anime", 5)]))),
+        ("/anime/3", anime("Recap", "Special", "2002-01-01", &related(&[("Full story", "anime", 1)]))),
+        ("/anime/4", anime("Movie", "Movie", "2005-01-01", &related(&[("Parent story", "anime", 1)]))),
+        ("/anime/5", anime("Season 3", "TV", "2004-01-01", &related(&[("Prequel", "anime", 2)]))),
+        ("/anime/6", anime("Origins", "TV", "2010-01-01", &related(&[("Sequel", "anime", 1)]))),
+    ];
+    let routes: Vec<(&str, &str)> = routes.iter().map(|(route, body)| (*route, body.as_str())).collect();
+    common::jikan(&routes)
+}
+
+fn mal_ids(entries: &[WatchOrderEntry]) -> Vec<u32> {
+    entries.iter().map(|entry| u32::from(entry.mal_id)).collect()
+}
+
+#[tokio::test]
+async fn should_order_the_series() {
+    let (jikan, fixtures) = jikan();
+    let watch_order = jikan.find_watch_order(2, WatchOrderQuery::new()).await.unwrap();
+    assert_eq!(mal_ids(&watch_order.chronological), vec![6, 1, 3, 2, 5, 4]);
+    assert_eq!(mal_ids(&watch_order.release), vec![1, 3, 2, 5, 4, 6]);
+    assert!(!watch_order.truncated);
+    let recap = watch_order.release.iter().find(|entry| entry.mal_id == 3).unwrap();
+    assert!(recap.summary);
+    assert_eq!(recap.anime_type, AnimeType::Special);
+    assert_eq!(recap.title, "Recap");
+    // Adaptations are not part of the series
+    assert!(fixtures.requests().iter().all(|request| !request.url.contains("/manga/")));
+}
+
+#[tokio::test]
+async fn should_exclude_summaries_and_types() {
+    let (jikan, _) = jikan();
+    let query = WatchOrderQuery::new()
+        .exclude_summaries(true)
+        .exclude_types(vec![AnimeType::Movie]);
+    let watch_order = jikan.find_anime(1).await.unwrap().get_watch_order(query).await.unwrap();
+    assert_eq!(mal_ids(&watch_order.chronological), vec![6, 1, 2, 5]);
+    assert_eq!(mal_ids(&watch_order.release), vec![1, 2, 5, 6]);
+}
+
+#[tokio::test]
+async fn should_stop_at_max_entries() {
+    let (jikan, _) = jikan();
+    let watch_order = jikan.find_watch_order(5, WatchOrderQuery::new().max_entries(2)).await.unwrap();
+    assert_eq!(mal_ids(&watch_order.chronological), vec![2, 5]);
+    assert!(watch_order.truncated);
+}